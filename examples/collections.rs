@@ -1,8 +1,653 @@
 // Collections in Rust
 // Learn about Vec, HashMap, HashSet, and more!
 
-use std::collections::{HashMap, HashSet, BTreeMap, VecDeque};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::sync::mpsc;
+use std::thread;
 
+// GENERIC HELPERS - Reusable functions that work on any slice
+
+// Returns the first element matching the predicate (like iter().find())
+fn first_where<T, F: Fn(&T) -> bool>(items: &[T], pred: F) -> Option<&T> {
+    items.iter().find(|item| pred(item))
+}
+
+// Returns the last element matching the predicate by searching from the back
+fn last_where<T, F: Fn(&T) -> bool>(items: &[T], pred: F) -> Option<&T> {
+    items.iter().rev().find(|item| pred(item))
+}
+
+// Inserts a copy of sep between every pair of elements: [a, b, c] -> [a, sep, b, sep, c]
+fn intersperse<T: Clone>(items: &[T], sep: T) -> Vec<T> {
+    let mut result = Vec::with_capacity(items.len() * 2);
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            result.push(sep.clone());
+        }
+        result.push(item.clone());
+    }
+    result
+}
+
+// Cyclically shifts elements n places to the left (n wraps around the length)
+fn rotate_left<T: Clone>(items: &[T], n: usize) -> Vec<T> {
+    if items.is_empty() {
+        return Vec::new();
+    }
+    let n = n % items.len();
+    let mut result = items[n..].to_vec();
+    result.extend_from_slice(&items[..n]);
+    result
+}
+
+// Cyclically shifts elements n places to the right
+fn rotate_right<T: Clone>(items: &[T], n: usize) -> Vec<T> {
+    if items.is_empty() {
+        return Vec::new();
+    }
+    rotate_left(items, items.len() - n % items.len())
+}
+
+// Counts occurrences; a BTreeMap keeps the keys in sorted order for us
+fn frequency_sorted<T: Ord + Clone>(items: &[T]) -> BTreeMap<T, usize> {
+    let mut counts = BTreeMap::new();
+    for item in items {
+        *counts.entry(item.clone()).or_insert(0) += 1;
+    }
+    counts
+}
+
+// Sums each consecutive chunk; the last chunk may be shorter
+fn chunked_sum(nums: &[i32], chunk_size: usize) -> Vec<i32> {
+    if chunk_size == 0 {
+        return Vec::new();
+    }
+    nums.chunks(chunk_size)
+        .map(|chunk| chunk.iter().sum())
+        .collect()
+}
+
+// Like first_where, but returns the position instead of the element
+fn find_index<T, F: Fn(&T) -> bool>(items: &[T], pred: F) -> Option<usize> {
+    items.iter().position(pred)
+}
+
+// True when every element equals the first (vacuously true for 0 or 1 elements)
+fn all_equal<T: PartialEq>(items: &[T]) -> bool {
+    match items.first() {
+        Some(first) => items.iter().all(|item| item == first),
+        None => true,
+    }
+}
+
+// Collapses runs of adjacent equal elements, like Unix `uniq`
+fn dedup_consecutive<T: PartialEq + Clone>(items: &[T]) -> Vec<T> {
+    let mut result: Vec<T> = Vec::with_capacity(items.len());
+    for item in items {
+        if result.last() != Some(item) {
+            result.push(item.clone());
+        }
+    }
+    result
+}
+
+// Keeps the LAST occurrence of each element: [1, 2, 1, 3] -> [2, 1, 3]
+fn dedup_keep_last<T: Eq + Hash + Clone>(items: &[T]) -> Vec<T> {
+    // Scan from the back keeping first sightings, then restore the original order
+    let mut seen = HashSet::new();
+    let mut result: Vec<T> = items
+        .iter()
+        .rev()
+        .filter(|item| seen.insert(*item))
+        .cloned()
+        .collect();
+    result.reverse();
+    result
+}
+
+// Drops repeats (first sighting wins) and collects into whatever C the caller asks for
+fn collect_unique<T: Eq + Hash + Clone, C: FromIterator<T>>(items: &[T]) -> C {
+    let mut seen = HashSet::new();
+    items
+        .iter()
+        .filter(|item| seen.insert(*item))
+        .cloned()
+        .collect()
+}
+
+// A custom collection: implementing FromIterator is all collect() needs
+#[derive(Debug, PartialEq)]
+struct ItemCount(usize);
+
+impl<T> FromIterator<T> for ItemCount {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        ItemCount(iter.into_iter().count())
+    }
+}
+
+// Run-length encoding: [a, a, b] -> [(a, 2), (b, 1)]
+fn rle_encode<T: PartialEq + Clone>(items: &[T]) -> Vec<(T, usize)> {
+    let mut runs: Vec<(T, usize)> = Vec::new();
+    for item in items {
+        match runs.last_mut() {
+            Some((value, count)) if value == item => *count += 1,
+            _ => runs.push((item.clone(), 1)),
+        }
+    }
+    runs
+}
+
+// Expands each (value, count) run back out
+fn rle_decode<T: Clone>(runs: &[(T, usize)]) -> Vec<T> {
+    runs.iter()
+        .flat_map(|(value, count)| std::iter::repeat_n(value.clone(), *count))
+        .collect()
+}
+
+// Prefix of values below threshold (stops at the first value that isn't)
+fn take_while_lt(nums: &[i32], threshold: i32) -> Vec<i32> {
+    nums.iter()
+        .copied()
+        .take_while(|&x| x < threshold)
+        .collect()
+}
+
+// Everything after that prefix
+fn drop_while_lt(nums: &[i32], threshold: i32) -> Vec<i32> {
+    nums.iter()
+        .copied()
+        .skip_while(|&x| x < threshold)
+        .collect()
+}
+
+// Splits before the first match: (everything before it, the match and the rest)
+fn split_at_predicate<T: Clone, F: Fn(&T) -> bool>(items: &[T], pred: F) -> (Vec<T>, Vec<T>) {
+    let index = find_index(items, pred).unwrap_or(items.len());
+    let (before, after) = items.split_at(index);
+    (before.to_vec(), after.to_vec())
+}
+
+// Like iter().zip(), but a length mismatch is an error instead of silent truncation
+fn strict_zip<A: Clone, B: Clone>(a: &[A], b: &[B]) -> Result<Vec<(A, B)>, String> {
+    if a.len() != b.len() {
+        return Err(format!(
+            "Length mismatch: first slice has {} elements, second has {}",
+            a.len(),
+            b.len()
+        ));
+    }
+    Ok(a.iter().cloned().zip(b.iter().cloned()).collect())
+}
+
+// Position-by-position changes as (index, old, new); None marks the side that's too short
+fn diff<T: PartialEq + Clone>(old: &[T], new: &[T]) -> Vec<(usize, Option<T>, Option<T>)> {
+    (0..old.len().max(new.len()))
+        .filter_map(|i| match (old.get(i), new.get(i)) {
+            (Some(a), Some(b)) if a == b => None,
+            (a, b) => Some((i, a.cloned(), b.cloned())),
+        })
+        .collect()
+}
+
+// All unordered pairs (items[i], items[j]) with i < j
+fn combinations_of_two<T: Clone>(items: &[T]) -> Vec<(T, T)> {
+    let mut pairs = Vec::new();
+    for (i, first) in items.iter().enumerate() {
+        for second in &items[i + 1..] {
+            pairs.push((first.clone(), second.clone()));
+        }
+    }
+    pairs
+}
+
+// Elements found in every set; no sets at all means nothing is common
+fn intersect_all(sets: &[HashSet<i32>]) -> HashSet<i32> {
+    let Some((first, rest)) = sets.split_first() else {
+        return HashSet::new();
+    };
+    first
+        .iter()
+        .filter(|n| rest.iter().all(|set| set.contains(n)))
+        .copied()
+        .collect()
+}
+
+// Owned copies of every contiguous window (slice::windows only lends them)
+fn windows_owned<T: Clone>(items: &[T], size: usize) -> Vec<Vec<T>> {
+    if size == 0 {
+        return Vec::new();
+    }
+    items.windows(size).map(|window| window.to_vec()).collect()
+}
+
+// Runs f over every sliding window and collects the results (sum, max, ...)
+fn window_apply<T: Clone, R, F: Fn(&[T]) -> R>(items: &[T], size: usize, f: F) -> Vec<R> {
+    if size == 0 {
+        return Vec::new();
+    }
+    items.windows(size).map(f).collect()
+}
+
+// Swaps rows and columns; every row must have the same length
+fn transpose<T: Clone>(grid: &[Vec<T>]) -> Result<Vec<Vec<T>>, String> {
+    let width = match grid.first() {
+        Some(row) => row.len(),
+        None => return Ok(Vec::new()),
+    };
+    if let Some(i) = grid.iter().position(|row| row.len() != width) {
+        return Err(format!(
+            "Row {} has {} elements, expected {}",
+            i,
+            grid[i].len(),
+            width
+        ));
+    }
+    Ok((0..width)
+        .map(|col| grid.iter().map(|row| row[col].clone()).collect())
+        .collect())
+}
+
+// Merges two already-sorted slices in one linear pass (the merge step of merge sort)
+fn merge_sorted<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        // <= keeps the merge stable: equal elements from `a` come first
+        if a[i] <= b[j] {
+            merged.push(a[i].clone());
+            i += 1;
+        } else {
+            merged.push(b[j].clone());
+            j += 1;
+        }
+    }
+    merged.extend_from_slice(&a[i..]);
+    merged.extend_from_slice(&b[j..]);
+    merged
+}
+
+// HashMap iteration order changes from run to run; sorting by key makes output repeatable
+fn map_to_sorted_vec<K: Ord + Clone, V: Clone>(map: &HashMap<K, V>) -> Vec<(K, V)> {
+    let mut entries: Vec<(K, V)> = map.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    entries
+}
+
+// STATISTICS - Numeric summaries over slices
+
+// p-th percentile (0-100) with linear interpolation between the closest ranks
+fn percentile(nums: &[f64], p: f64) -> Option<f64> {
+    if nums.is_empty() || !(0.0..=100.0).contains(&p) {
+        return None;
+    }
+    let mut sorted = nums.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let fraction = rank - lower as f64;
+    Some(sorted[lower] + (sorted[upper] - sorted[lower]) * fraction)
+}
+
+// Mean of each consecutive chunk; a shorter final chunk is averaged over its own length
+fn chunk_averages(nums: &[f64], chunk_size: usize) -> Vec<f64> {
+    if chunk_size == 0 {
+        return Vec::new();
+    }
+    nums.chunks(chunk_size)
+        .map(|chunk| chunk.iter().sum::<f64>() / chunk.len() as f64)
+        .collect()
+}
+
+// Minimum of each sliding window; empty when window is 0 or longer than the slice
+fn rolling_min(nums: &[i32], window: usize) -> Vec<i32> {
+    // window_apply never hands over an empty window, so the unwrap can't fail
+    window_apply(nums, window, |w| *w.iter().min().unwrap())
+}
+
+// Exponential moving average: each output is alpha * current + (1 - alpha) * previous output
+fn ema(nums: &[f64], alpha: f64) -> Result<Vec<f64>, String> {
+    // Written so NaN fails the check too
+    if !(alpha > 0.0 && alpha <= 1.0) {
+        return Err(format!("alpha must be in (0, 1], got {}", alpha));
+    }
+    let mut averages: Vec<f64> = Vec::with_capacity(nums.len());
+    for &n in nums {
+        let next = match averages.last() {
+            Some(&previous) => alpha * n + (1.0 - alpha) * previous,
+            None => n,
+        };
+        averages.push(next);
+    }
+    Ok(averages)
+}
+
+// Index and value of the largest element (first one wins ties)
+fn argmax<T: PartialOrd + Copy>(nums: &[T]) -> Option<(usize, T)> {
+    let mut best: Option<(usize, T)> = None;
+    for (i, &value) in nums.iter().enumerate() {
+        if best.is_none_or(|(_, current)| value > current) {
+            best = Some((i, value));
+        }
+    }
+    best
+}
+
+// Index and value of the smallest element (first one wins ties)
+fn argmin<T: PartialOrd + Copy>(nums: &[T]) -> Option<(usize, T)> {
+    let mut best: Option<(usize, T)> = None;
+    for (i, &value) in nums.iter().enumerate() {
+        if best.is_none_or(|(_, current)| value < current) {
+            best = Some((i, value));
+        }
+    }
+    best
+}
+
+// Most frequent element; on a tie the one seen first wins
+fn mode<T: Eq + Hash + Clone>(items: &[T]) -> Option<T> {
+    let mut counts: HashMap<&T, usize> = HashMap::new();
+    for item in items {
+        *counts.entry(item).or_insert(0) += 1;
+    }
+    // Walk in original order so the earliest item wins ties
+    let mut best: Option<(&T, usize)> = None;
+    for item in items {
+        let count = counts[item];
+        if best.is_none_or(|(_, best_count)| count > best_count) {
+            best = Some((item, count));
+        }
+    }
+    best.map(|(item, _)| item.clone())
+}
+
+// MEMOIZATION - A struct that owns its own HashMap cache
+struct FibCache {
+    cache: HashMap<u32, u64>,
+}
+
+impl FibCache {
+    // fib(93) is the largest Fibonacci number that fits in a u64
+    const MAX_N: u32 = 93;
+
+    fn new() -> Self {
+        FibCache {
+            cache: HashMap::new(),
+        }
+    }
+
+    // Returns u64::MAX instead of overflowing for n > 93
+    fn fib(&mut self, n: u32) -> u64 {
+        if n > Self::MAX_N {
+            return u64::MAX;
+        }
+        if n < 2 {
+            return n as u64;
+        }
+        if let Some(&value) = self.cache.get(&n) {
+            return value;
+        }
+        let value = self.fib(n - 1) + self.fib(n - 2);
+        self.cache.insert(n, value);
+        value
+    }
+
+    fn cache_size(&self) -> usize {
+        self.cache.len()
+    }
+}
+
+// Generic version: cache the results of any Fn(u64) -> u64
+struct Memoized<F: Fn(u64) -> u64> {
+    func: F,
+    cache: HashMap<u64, u64>,
+}
+
+impl<F: Fn(u64) -> u64> Memoized<F> {
+    fn new(func: F) -> Self {
+        Memoized {
+            func,
+            cache: HashMap::new(),
+        }
+    }
+
+    // Only runs the wrapped function the first time each argument is seen
+    fn call(&mut self, n: u64) -> u64 {
+        let func = &self.func;
+        *self.cache.entry(n).or_insert_with(|| func(n))
+    }
+}
+
+// STACK - A Vec is a stack: push and pop both work on the end
+// Each opener pushes the closer it expects; each closer must match the top.
+fn is_balanced(s: &str) -> bool {
+    let mut expected = Vec::new();
+    for c in s.chars() {
+        match c {
+            '(' => expected.push(')'),
+            '[' => expected.push(']'),
+            '{' => expected.push('}'),
+            ')' | ']' | '}' if expected.pop() != Some(c) => return false,
+            _ => {} // Matched closer, or not a bracket at all
+        }
+    }
+    expected.is_empty()
+}
+
+// PRIORITY QUEUE - BinaryHeap pops the "greatest" item, so Ord defines priority
+#[derive(Debug, PartialEq, Eq)]
+struct Task {
+    name: String,
+    priority: u32,
+}
+
+impl Ord for Task {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Higher priority first; ties broken alphabetically (reversed so "a" is greatest)
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.name.cmp(&self.name))
+    }
+}
+
+impl PartialOrd for Task {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Task names in the order a BinaryHeap would run them
+fn schedule(tasks: Vec<Task>) -> Vec<String> {
+    let mut heap: BinaryHeap<Task> = tasks.into_iter().collect();
+    let mut order = Vec::with_capacity(heap.len());
+    while let Some(task) = heap.pop() {
+        order.push(task.name);
+    }
+    order
+}
+
+// WORD COUNTING - Sequential and multi-threaded versions
+fn word_frequency(text: &str) -> HashMap<String, u32> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_string()).or_insert(0) += 1;
+    }
+    counts
+}
+
+// One thread per text; each sends its local counts over a channel to be merged
+fn parallel_word_count(texts: Vec<String>) -> HashMap<String, u32> {
+    let (sender, receiver) = mpsc::channel();
+    let mut handles = Vec::with_capacity(texts.len());
+
+    for text in texts {
+        let sender = sender.clone();
+        handles.push(thread::spawn(move || {
+            sender
+                .send(word_frequency(&text))
+                .expect("receiver outlives the workers");
+        }));
+    }
+    drop(sender); // Close our copy so the loop below ends once every worker is done
+
+    let mut totals = HashMap::new();
+    for local in receiver {
+        for (word, count) in local {
+            *totals.entry(word).or_insert(0) += count;
+        }
+    }
+    for handle in handles {
+        handle.join().expect("word count thread panicked");
+    }
+    totals
+}
+
+// Like word_frequency, but counts which word follows which: "the" -> {"cat": 2, "dog": 1}
+struct MarkovChain {
+    transitions: HashMap<String, HashMap<String, u32>>,
+}
+
+impl MarkovChain {
+    // Words are split the same way word_frequency splits them
+    fn train(text: &str) -> Self {
+        let mut transitions: HashMap<String, HashMap<String, u32>> = HashMap::new();
+        let words: Vec<&str> = text.split_whitespace().collect();
+        for pair in words.windows(2) {
+            *transitions
+                .entry(pair[0].to_string())
+                .or_default()
+                .entry(pair[1].to_string())
+                .or_insert(0) += 1;
+        }
+        MarkovChain { transitions }
+    }
+
+    // Most frequent successor; ties go to the alphabetically first word so results are stable
+    fn next_word(&self, current: &str) -> Option<&str> {
+        self.transitions
+            .get(current)?
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(word, _)| word.as_str())
+    }
+}
+
+// Splits the work into roughly equal chunks, one scoped thread per chunk.
+// Scoped threads may borrow `nums` because they're joined before the scope ends.
+fn parallel_sum(nums: Vec<i64>, threads: usize) -> i64 {
+    if nums.is_empty() {
+        return 0;
+    }
+    let threads = threads.max(1);
+    let chunk_size = nums.len().div_ceil(threads);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = nums
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().sum::<i64>()))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("sum thread panicked"))
+            .sum()
+    })
+}
+
+// CUSTOM ITERATOR - start, start + step, start + 2*step, ... computed lazily.
+// Only the current value is stored, so adapters like take() decide when to stop.
+struct Counter {
+    next: Option<u64>, // None once the sequence would overflow u64
+    step: u64,
+}
+
+impl Counter {
+    fn new(start: u64, step: u64) -> Self {
+        Counter {
+            next: Some(start),
+            step,
+        }
+    }
+}
+
+impl Iterator for Counter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let current = self.next?;
+        self.next = current.checked_add(self.step);
+        Some(current)
+    }
+}
+
+// RECURSIVE DATA STRUCTURE - A binary search tree; Box gives each child a fixed size
+struct TreeNode<T> {
+    value: T,
+    left: Option<Box<TreeNode<T>>>,
+    right: Option<Box<TreeNode<T>>>,
+}
+
+struct BinaryTree<T: Ord> {
+    root: Option<Box<TreeNode<T>>>,
+    len: usize,
+}
+
+impl<T: Ord> BinaryTree<T> {
+    fn new() -> Self {
+        BinaryTree { root: None, len: 0 }
+    }
+
+    // Smaller values go left, larger go right; duplicates are skipped (returns false)
+    fn insert(&mut self, value: T) -> bool {
+        let mut current = &mut self.root;
+        while let Some(node) = current {
+            current = match value.cmp(&node.value) {
+                Ordering::Less => &mut node.left,
+                Ordering::Greater => &mut node.right,
+                Ordering::Equal => return false,
+            };
+        }
+        *current = Some(Box::new(TreeNode {
+            value,
+            left: None,
+            right: None,
+        }));
+        self.len += 1;
+        true
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    // Left subtree, then the node, then the right subtree = sorted order
+    fn in_order(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        fn walk<T: Clone>(node: &Option<Box<TreeNode<T>>>, out: &mut Vec<T>) {
+            if let Some(node) = node {
+                walk(&node.left, out);
+                out.push(node.value.clone());
+                walk(&node.right, out);
+            }
+        }
+
+        let mut values = Vec::with_capacity(self.len);
+        walk(&self.root, &mut values);
+        values
+    }
+}
+
+impl<T: Ord> Default for BinaryTree<T> {
+    fn default() -> Self {
+        BinaryTree::new()
+    }
+}
+
+#[allow(clippy::vec_init_then_push)] // The vector demo shows push() one step at a time
 fn main() {
     println!("=== Collections in Rust ===\n");
 
@@ -37,6 +682,10 @@ fn main() {
     let last = fruits.pop();
     println!("Popped: {:?}, Remaining: {:?}", last, fruits);
 
+    // Rotating (returns a new vector)
+    println!("Rotated left by 1: {:?}", rotate_left(&fruits, 1));
+    println!("Rotated right by 1: {:?}", rotate_right(&fruits, 1));
+
     // Iterating
     print!("Fruits: ");
     for fruit in &fruits {
@@ -52,7 +701,7 @@ fn main() {
     scores.insert(String::from("Bob"), 87);
     scores.insert(String::from("Charlie"), 92);
 
-    println!("Scores: {:?}", scores);
+    println!("Scores: {:?}", map_to_sorted_vec(&scores));
 
     // Accessing values
     let alice_score = scores.get("Alice");
@@ -63,12 +712,12 @@ fn main() {
 
     // Updating values
     scores.insert(String::from("Alice"), 98); // Overwrites
-    println!("After update: {:?}", scores);
+    println!("After update: {:?}", map_to_sorted_vec(&scores));
 
     // Only insert if key doesn't exist
     scores.entry(String::from("David")).or_insert(85);
     scores.entry(String::from("Alice")).or_insert(100); // Won't overwrite
-    println!("After entry: {:?}", scores);
+    println!("After entry: {:?}", map_to_sorted_vec(&scores));
 
     // Update based on old value
     let alice = scores.entry(String::from("Alice")).or_insert(0);
@@ -77,7 +726,7 @@ fn main() {
 
     // Iterating
     println!("All scores:");
-    for (name, score) in &scores {
+    for (name, score) in map_to_sorted_vec(&scores) {
         println!("  {}: {}", name, score);
     }
     println!();
@@ -112,6 +761,17 @@ fn main() {
     println!("Union: {:?}", union);
     println!("Intersection: {:?}", intersection);
     println!("Difference (1-2): {:?}", difference);
+
+    let set3: HashSet<i32> = vec![4, 3, 9].into_iter().collect();
+    let mut common: Vec<i32> = intersect_all(&[set1.clone(), set2.clone(), set3])
+        .into_iter()
+        .collect();
+    common.sort();
+    println!("In all three sets: {:?}", common);
+
+    // Every possible pairing from a list
+    let players = ["Ann", "Ben", "Cat"];
+    println!("Matchups: {:?}", combinations_of_two(&players));
     println!();
 
     // 4. VECTOR OPERATIONS
@@ -123,6 +783,10 @@ fn main() {
     // Sorting
     nums.sort();
     println!("Sorted: {:?}", nums);
+    println!(
+        "Merged with [4, 6, 10]: {:?}",
+        merge_sorted(&nums, &[4, 6, 10])
+    );
 
     // Reverse
     nums.reverse();
@@ -140,15 +804,35 @@ fn main() {
     let sum: i32 = nums.iter().sum();
     println!("Sum: {}", sum);
 
+    // Sum in chunks of 4
+    println!("Chunked sums (4): {:?}", chunked_sum(&nums, 4));
+    println!("Windows of 3: {:?}", windows_owned(&nums, 3));
+    let window_sums = window_apply(&nums, 3, |w| w.iter().sum::<i32>());
+    let window_maxes = window_apply(&nums, 3, |w| w.iter().max().copied());
+    println!("Window sums: {:?}", window_sums);
+    println!("Window maxes: {:?}", window_maxes);
+
     // Find
     let first_even = nums.iter().find(|&&x| x % 2 == 0);
     println!("First even: {:?}", first_even);
+    println!(
+        "Index of first even: {:?}",
+        find_index(&nums, |&x| x % 2 == 0)
+    );
+
+    // Generic first/last helpers
+    println!(
+        "first_where even: {:?}",
+        first_where(&nums, |&x| x % 2 == 0)
+    );
+    println!("last_where even: {:?}", last_where(&nums, |&x| x % 2 == 0));
 
     // Any / All
     let has_large = nums.iter().any(|&x| x > 5);
     let all_positive = nums.iter().all(|&x| x > 0);
     println!("Has number > 5? {}", has_large);
     println!("All positive? {}", all_positive);
+    println!("All equal? {}", all_equal(&nums));
     println!();
 
     // 5. BTREEMAP - Sorted key-value pairs
@@ -163,6 +847,10 @@ fn main() {
     for (name, score) in &sorted_scores {
         println!("  {}: {}", name, score);
     }
+
+    // Sorted frequency counts
+    let letters: Vec<char> = "mississippi".chars().collect();
+    println!("Letter counts (sorted): {:?}", frequency_sorted(&letters));
     println!();
 
     // 6. VECDEQUE - Double-ended queue
@@ -189,6 +877,45 @@ fn main() {
     println!("Popped back: {:?}, Remaining: {:?}", back, queue);
     println!();
 
+    // Vec as a stack - matching brackets
+    println!("--- Vec<T> as a Stack ---");
+    for expr in ["{ a[i] * (b + c) }", "(]", "((x)"] {
+        println!("'{}' balanced? {}", expr, is_balanced(expr));
+    }
+    println!();
+
+    // BINARYHEAP - Priority queue
+    println!("--- BinaryHeap<T> (Priority Queue) ---");
+
+    let tasks = vec![
+        Task {
+            name: String::from("write docs"),
+            priority: 1,
+        },
+        Task {
+            name: String::from("fix outage"),
+            priority: 10,
+        },
+        Task {
+            name: String::from("review PR"),
+            priority: 5,
+        },
+    ];
+    println!("Execution order: {:?}", schedule(tasks));
+    println!();
+
+    // BINARY SEARCH TREE - Recursive structure
+    println!("--- Binary Search Tree ---");
+
+    let mut tree = BinaryTree::new();
+    for value in [50, 30, 70, 20, 40, 60, 80, 30] {
+        if !tree.insert(value) {
+            println!("Skipped duplicate {}", value);
+        }
+    }
+    println!("In-order ({} values): {:?}", tree.len(), tree.in_order());
+    println!();
+
     // 7. WORD FREQUENCY COUNTER (Practical Example)
     println!("--- Word Frequency Counter ---");
 
@@ -204,6 +931,46 @@ fn main() {
     for (word, count) in &word_count {
         println!("  '{}': {}", word, count);
     }
+
+    // Same count, split across threads
+    let chunks = vec![
+        String::from("the quick brown fox"),
+        String::from("jumps over the lazy dog"),
+        String::from("the fox"),
+    ];
+    let parallel = parallel_word_count(chunks);
+    println!("Parallel count of 'the': {:?}", parallel.get("the"));
+    println!(
+        "Parallel sum of 1..=1000: {}",
+        parallel_sum((1..=1000).collect(), 4)
+    );
+
+    // Predict the next word from what followed it in the training text
+    let chain = MarkovChain::train(text);
+    for word in ["the", "fox"] {
+        println!(
+            "After '{}' usually comes: {:?}",
+            word,
+            chain.next_word(word)
+        );
+    }
+    println!();
+
+    // Memoized Fibonacci backed by a HashMap
+    println!("--- Memoized Fibonacci ---");
+    let mut fib_cache = FibCache::new();
+    println!("fib(50) = {}", fib_cache.fib(50));
+    println!("fib(93) = {}", fib_cache.fib(93));
+    println!("fib(100) = {} (capped at u64::MAX)", fib_cache.fib(100));
+    println!("Cached values: {}", fib_cache.cache_size());
+
+    // Any closure can be memoized
+    let mut slow_square = Memoized::new(|n| {
+        println!("  (computing {}²...)", n);
+        (0..n).map(|_| n).sum()
+    });
+    println!("square(12) = {}", slow_square.call(12));
+    println!("square(12) = {} (cached)", slow_square.call(12));
     println!();
 
     // 8. GROUPING DATA (Practical Example)
@@ -218,9 +985,17 @@ fn main() {
         ("Frank", "A"),
     ];
 
+    let names = ["Alice", "Bob", "Charlie"];
+    let grades = ["A", "B"];
+    match strict_zip(&names, &grades) {
+        Ok(pairs) => println!("Paired: {:?}", pairs),
+        Err(e) => println!("❌ {}", e),
+    }
+
     let mut grade_groups: HashMap<&str, Vec<&str>> = HashMap::new();
 
     for (student, grade) in students {
+        #[allow(clippy::unwrap_or_default)] // Spells out the value being inserted
         grade_groups.entry(grade).or_insert(Vec::new()).push(student);
     }
 
@@ -232,6 +1007,15 @@ fn main() {
     // 9. CHAINING ITERATOR METHODS
     println!("--- Iterator Chains ---");
 
+    // A custom iterator plugs into the same adapters as the built-in ones
+    let odds: Vec<u64> = Counter::new(1, 2).take(5).collect();
+    println!("First five odd numbers: {:?}", odds);
+    let multiples_of_3: u64 = Counter::new(3, 3).filter(|n| n % 2 == 0).take(3).sum();
+    println!(
+        "Sum of the first three even multiples of 3: {}",
+        multiples_of_3
+    );
+
     let numbers = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
 
     let result: i32 = numbers
@@ -244,6 +1028,16 @@ fn main() {
     println!("Sum of squares of first 3 even numbers: {}", result);
     println!("Process: filter evens → square → take 3 → sum");
     println!("Numbers: [1,2,3,4,5,6,7,8,9,10] → [2,4,6] → [4,16,36] → 56");
+
+    // take_while / skip_while split a sequence at the first failing element
+    let readings = [1, 3, 5, 8, 2, 9];
+    println!("Readings: {:?}", readings);
+    println!("Taken while < 5: {:?}", take_while_lt(&readings, 5));
+    println!("Dropped while < 5: {:?}", drop_while_lt(&readings, 5));
+    println!(
+        "Split at first > 7: {:?}",
+        split_at_predicate(&readings, |&x| x > 7)
+    );
     println!();
 
     // 10. COLLECTING INTO DIFFERENT COLLECTIONS
@@ -252,6 +1046,7 @@ fn main() {
     let nums = vec![1, 2, 3, 2, 1, 4, 3];
 
     // To Vec
+    #[allow(clippy::iter_cloned_collect)] // Same collect() pattern as the other targets below
     let as_vec: Vec<i32> = nums.iter().copied().collect();
     println!("As Vec: {:?}", as_vec);
 
@@ -259,11 +1054,68 @@ fn main() {
     let as_set: HashSet<i32> = nums.iter().copied().collect();
     println!("As HashSet (unique): {:?}", as_set);
 
+    // Only adjacent duplicates removed, order kept
+    println!("Consecutive dedup: {:?}", dedup_consecutive(&nums));
+    println!("Dedup keeping last: {:?}", dedup_keep_last(&nums));
+    println!("Unique into Vec: {:?}", collect_unique::<_, Vec<_>>(&nums));
+    println!(
+        "Unique into ItemCount: {:?}",
+        collect_unique::<_, ItemCount>(&nums)
+    );
+
+    // What changed between two versions of a list
+    let before = ["milk", "eggs", "bread"];
+    let after = ["milk", "butter", "bread", "jam"];
+    for (i, old, new) in diff(&before, &after) {
+        println!("Changed at {}: {:?} -> {:?}", i, old, new);
+    }
+
+    // Nested vectors as a grid
+    let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    println!("Grid: {:?}", grid);
+    println!("Transposed: {:?}", transpose(&grid));
+
     // To String
     let words = vec!["Hello", "Rust", "World"];
     let sentence = words.join(" ");
     println!("As String: {}", sentence);
 
+    // Run-length encoding
+    let letters: Vec<char> = "aaabccdddd".chars().collect();
+    let encoded = rle_encode(&letters);
+    println!("RLE encoded: {:?}", encoded);
+    println!(
+        "RLE decoded: {}",
+        rle_decode(&encoded).iter().collect::<String>()
+    );
+
+    // Intersperse works on any element type, not just strings
+    let with_commas: String = intersperse(&words, ", ").concat();
+    println!("Interspersed: {}", with_commas);
+    println!("Interspersed numbers: {:?}", intersperse(&[1, 2, 3], 0));
+    println!();
+
+    // 11. STATISTICS
+    println!("--- Statistics ---");
+
+    let response_times = [120.0, 95.0, 180.0, 110.0, 300.0, 105.0];
+    println!("Response times: {:?}", response_times);
+    println!("Median (p50): {:?}", percentile(&response_times, 50.0));
+    println!("p90: {:?}", percentile(&response_times, 90.0));
+    println!("Averages per 4: {:?}", chunk_averages(&response_times, 4));
+    println!("EMA (alpha 0.5): {:?}", ema(&response_times, 0.5));
+    let queue_lengths = [5, 3, 8, 2, 7, 6];
+    println!(
+        "Rolling min of {:?} (3): {:?}",
+        queue_lengths,
+        rolling_min(&queue_lengths, 3)
+    );
+    println!("Slowest (index, value): {:?}", argmax(&response_times));
+    println!("Fastest (index, value): {:?}", argmin(&response_times));
+
+    let votes = ["tea", "coffee", "tea", "water", "coffee", "tea"];
+    println!("Most popular: {:?}", mode(&votes));
+
     println!("\n🎉 You've mastered Rust collections!");
 }
 
@@ -319,4 +1171,704 @@ mod tests {
         assert_eq!(counts.get("hello"), Some(&2));
         assert_eq!(counts.get("world"), Some(&1));
     }
+
+    #[test]
+    fn test_first_and_last_where() {
+        let nums = [1, 2, 3, 4, 5, 6, 7];
+        assert_eq!(first_where(&nums, |&x| x % 2 == 0), Some(&2));
+        assert_eq!(last_where(&nums, |&x| x % 2 == 0), Some(&6));
+
+        // No match
+        assert_eq!(first_where(&nums, |&x| x > 100), None);
+        assert_eq!(last_where(&nums, |&x| x > 100), None);
+
+        // Empty slice
+        let empty: [i32; 0] = [];
+        assert_eq!(first_where(&empty, |_| true), None);
+        assert_eq!(last_where(&empty, |_| true), None);
+    }
+
+    #[test]
+    fn test_intersperse() {
+        assert_eq!(
+            intersperse(&['a', 'b', 'c'], 'x'),
+            vec!['a', 'x', 'b', 'x', 'c']
+        );
+
+        // Single element gets no separator
+        assert_eq!(intersperse(&[1], 0), vec![1]);
+
+        // Empty stays empty
+        let empty: [i32; 0] = [];
+        assert!(intersperse(&empty, 0).is_empty());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let nums = [1, 2, 3, 4];
+        assert_eq!(rotate_left(&nums, 1), vec![2, 3, 4, 1]);
+        assert_eq!(rotate_right(&nums, 1), vec![4, 1, 2, 3]);
+
+        // Rotating by the full length is a no-op
+        assert_eq!(rotate_left(&nums, 4), vec![1, 2, 3, 4]);
+        assert_eq!(rotate_right(&nums, 4), vec![1, 2, 3, 4]);
+
+        // n larger than the length wraps around
+        assert_eq!(rotate_left(&nums, 5), vec![2, 3, 4, 1]);
+        assert_eq!(rotate_right(&nums, 6), vec![3, 4, 1, 2]);
+
+        let empty: [i32; 0] = [];
+        assert!(rotate_left(&empty, 3).is_empty());
+        assert!(rotate_right(&empty, 3).is_empty());
+    }
+
+    #[test]
+    fn test_frequency_sorted() {
+        let counts = frequency_sorted(&[3, 1, 2, 3, 1, 3]);
+        let entries: Vec<(i32, usize)> = counts.into_iter().collect();
+        assert_eq!(entries, vec![(1, 2), (2, 1), (3, 3)]);
+
+        let letters: Vec<char> = "banana".chars().collect();
+        let counts = frequency_sorted(&letters);
+        let keys: Vec<&char> = counts.keys().collect();
+        assert_eq!(keys, vec![&'a', &'b', &'n']);
+        assert_eq!(counts[&'a'], 3);
+        assert_eq!(counts[&'b'], 1);
+        assert_eq!(counts[&'n'], 2);
+
+        let empty: [i32; 0] = [];
+        assert!(frequency_sorted(&empty).is_empty());
+    }
+
+    #[test]
+    fn test_chunked_sum() {
+        assert_eq!(chunked_sum(&[1, 2, 3, 4, 5, 6], 2), vec![3, 7, 11]);
+
+        // Last chunk is shorter
+        assert_eq!(chunked_sum(&[1, 2, 3, 4, 5], 2), vec![3, 7, 5]);
+
+        // One chunk covering everything
+        assert_eq!(chunked_sum(&[1, 2, 3], 10), vec![6]);
+
+        assert!(chunked_sum(&[], 3).is_empty());
+        assert!(chunked_sum(&[1, 2, 3], 0).is_empty());
+    }
+
+    fn fib_iterative(n: u32) -> u64 {
+        let (mut a, mut b) = (0u64, 1u64);
+        for _ in 0..n {
+            let next = a.wrapping_add(b); // b may overflow past fib(93); a never does
+            a = b;
+            b = next;
+        }
+        a
+    }
+
+    #[test]
+    fn test_fib_cache_matches_iterative() {
+        let mut cache = FibCache::new();
+        for n in 0..=FibCache::MAX_N {
+            assert_eq!(cache.fib(n), fib_iterative(n));
+        }
+        assert_eq!(cache.fib(94), u64::MAX);
+    }
+
+    #[test]
+    fn test_fib_cache_reuse() {
+        let mut cache = FibCache::new();
+        assert_eq!(cache.fib(30), 832040);
+        let size = cache.cache_size();
+        assert!(size > 0);
+
+        // Repeated and smaller calls hit the cache without growing it
+        assert_eq!(cache.fib(30), 832040);
+        assert_eq!(cache.fib(20), 6765);
+        assert_eq!(cache.cache_size(), size);
+    }
+
+    #[test]
+    fn test_take_and_drop_while() {
+        // Crosses the threshold; later small values stay in the remainder
+        let nums = [1, 3, 5, 8, 2];
+        assert_eq!(take_while_lt(&nums, 5), vec![1, 3]);
+        assert_eq!(drop_while_lt(&nums, 5), vec![5, 8, 2]);
+
+        // Entirely below
+        assert_eq!(take_while_lt(&[1, 2, 3], 10), vec![1, 2, 3]);
+        assert!(drop_while_lt(&[1, 2, 3], 10).is_empty());
+
+        // Entirely above
+        assert!(take_while_lt(&[20, 30], 10).is_empty());
+        assert_eq!(drop_while_lt(&[20, 30], 10), vec![20, 30]);
+    }
+
+    #[test]
+    fn test_combinations_of_two() {
+        assert_eq!(
+            combinations_of_two(&['a', 'b', 'c']),
+            vec![('a', 'b'), ('a', 'c'), ('b', 'c')]
+        );
+        assert_eq!(combinations_of_two(&[1, 2, 3, 4]).len(), 6);
+        assert!(combinations_of_two(&[1]).is_empty());
+
+        let empty: [i32; 0] = [];
+        assert!(combinations_of_two(&empty).is_empty());
+    }
+
+    #[test]
+    fn test_find_index() {
+        let nums = [1, 3, 4, 5, 6];
+        assert_eq!(find_index(&nums, |&x| x % 2 == 0), Some(2));
+        assert_eq!(find_index(&nums, |&x| x > 100), None);
+        assert_eq!(find_index(&nums, |&x| x == 1), Some(0));
+
+        let empty: [i32; 0] = [];
+        assert_eq!(find_index(&empty, |_| true), None);
+    }
+
+    fn task(name: &str, priority: u32) -> Task {
+        Task {
+            name: String::from(name),
+            priority,
+        }
+    }
+
+    #[test]
+    fn test_schedule_by_priority() {
+        let order = schedule(vec![task("low", 1), task("high", 9), task("mid", 5)]);
+        assert_eq!(order, vec!["high", "mid", "low"]);
+    }
+
+    #[test]
+    fn test_schedule_ties() {
+        // Equal priorities come out alphabetically, regardless of input order
+        let order = schedule(vec![
+            task("c", 3),
+            task("a", 3),
+            task("urgent", 7),
+            task("b", 3),
+        ]);
+        assert_eq!(order, vec!["urgent", "a", "b", "c"]);
+        assert!(schedule(vec![]).is_empty());
+    }
+
+    #[test]
+    fn test_split_at_predicate() {
+        let nums = [1, 2, 10, 3, 20];
+        assert_eq!(
+            split_at_predicate(&nums, |&x| x >= 10),
+            (vec![1, 2], vec![10, 3, 20])
+        );
+
+        // No match: everything goes in the first vec
+        assert_eq!(
+            split_at_predicate(&nums, |&x| x > 100),
+            (vec![1, 2, 10, 3, 20], vec![])
+        );
+
+        // Match at index 0
+        assert_eq!(
+            split_at_predicate(&nums, |&x| x == 1),
+            (vec![], vec![1, 2, 10, 3, 20])
+        );
+    }
+
+    #[test]
+    fn test_all_equal() {
+        assert!(all_equal(&[7, 7, 7, 7]));
+        assert!(!all_equal(&[7, 7, 8, 7]));
+        assert!(all_equal(&[1]));
+
+        let empty: [i32; 0] = [];
+        assert!(all_equal(&empty));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        assert_eq!(
+            transpose(&grid),
+            Ok(vec![vec![1, 4], vec![2, 5], vec![3, 6]])
+        );
+
+        let square = vec![vec!['a', 'b'], vec!['c', 'd']];
+        assert_eq!(transpose(&square), Ok(vec![vec!['a', 'c'], vec!['b', 'd']]));
+
+        let empty: Vec<Vec<i32>> = vec![];
+        assert_eq!(transpose(&empty), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_transpose_ragged() {
+        let ragged = vec![vec![1, 2], vec![3], vec![4, 5]];
+        assert_eq!(
+            transpose(&ragged),
+            Err(String::from("Row 1 has 1 elements, expected 2"))
+        );
+    }
+
+    #[test]
+    fn test_dedup_consecutive() {
+        assert_eq!(dedup_consecutive(&[1, 1, 2, 2, 1]), vec![1, 2, 1]);
+        assert_eq!(dedup_consecutive(&['z', 'z', 'z']), vec!['z']);
+        assert_eq!(dedup_consecutive(&[1, 2, 1, 2]), vec![1, 2, 1, 2]);
+
+        let empty: [i32; 0] = [];
+        assert!(dedup_consecutive(&empty).is_empty());
+    }
+
+    #[test]
+    fn test_percentile() {
+        let nums = [15.0, 20.0, 35.0, 40.0, 50.0];
+        // 50th percentile is the median
+        assert_eq!(percentile(&nums, 50.0), Some(35.0));
+        assert_eq!(percentile(&[4.0, 1.0, 3.0, 2.0], 50.0), Some(2.5));
+
+        assert_eq!(percentile(&nums, 0.0), Some(15.0));
+        assert_eq!(percentile(&nums, 100.0), Some(50.0));
+        assert_eq!(percentile(&nums, 25.0), Some(20.0));
+    }
+
+    #[test]
+    fn test_percentile_invalid() {
+        assert_eq!(percentile(&[1.0, 2.0], -1.0), None);
+        assert_eq!(percentile(&[1.0, 2.0], 100.5), None);
+        assert_eq!(percentile(&[], 50.0), None);
+    }
+
+    #[test]
+    fn test_memoized_calls_once_per_argument() {
+        use std::cell::Cell;
+
+        let calls = Cell::new(0);
+        let mut double = Memoized::new(|n| {
+            calls.set(calls.get() + 1);
+            n * 2
+        });
+
+        assert_eq!(double.call(5), 10);
+        assert_eq!(double.call(5), 10);
+        assert_eq!(calls.get(), 1);
+
+        assert_eq!(double.call(7), 14);
+        assert_eq!(double.call(5), 10);
+        assert_eq!(double.call(7), 14);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_windows_owned() {
+        assert_eq!(
+            windows_owned(&[1, 2, 3, 4], 2),
+            vec![vec![1, 2], vec![2, 3], vec![3, 4]]
+        );
+        assert_eq!(windows_owned(&[1, 2, 3], 3), vec![vec![1, 2, 3]]);
+        assert!(windows_owned(&[1, 2, 3], 4).is_empty());
+        assert!(windows_owned(&[1, 2, 3], 0).is_empty());
+    }
+
+    #[test]
+    fn test_mode() {
+        assert_eq!(mode(&[1, 2, 2, 3, 2, 1]), Some(2));
+
+        // 'b' and 'a' both appear twice; 'b' was seen first
+        assert_eq!(mode(&['b', 'a', 'a', 'b', 'c']), Some('b'));
+
+        let empty: [i32; 0] = [];
+        assert_eq!(mode(&empty), None);
+    }
+
+    #[test]
+    fn test_binary_tree_in_order_is_sorted() {
+        let mut tree = BinaryTree::new();
+        for value in [8, 3, 10, 1, 6, 14, 4, 7, 13] {
+            assert!(tree.insert(value));
+        }
+        assert_eq!(tree.in_order(), vec![1, 3, 4, 6, 7, 8, 10, 13, 14]);
+        assert_eq!(tree.len(), 9);
+
+        let empty: BinaryTree<i32> = BinaryTree::default();
+        assert!(empty.in_order().is_empty());
+    }
+
+    #[test]
+    fn test_binary_tree_skips_duplicates() {
+        let mut tree = BinaryTree::new();
+        assert!(tree.insert("m"));
+        assert!(tree.insert("c"));
+        assert!(!tree.insert("m"));
+        assert!(!tree.insert("c"));
+        assert_eq!(tree.in_order(), vec!["c", "m"]);
+        assert_eq!(tree.len(), 2);
+    }
+
+    #[test]
+    fn test_chunk_averages() {
+        assert_eq!(chunk_averages(&[1.0, 3.0, 5.0, 7.0], 2), vec![2.0, 6.0]);
+
+        // Final chunk has only one element
+        assert_eq!(
+            chunk_averages(&[1.0, 3.0, 5.0, 7.0, 10.0], 2),
+            vec![2.0, 6.0, 10.0]
+        );
+
+        assert_eq!(chunk_averages(&[4.0, 5.5, 6.0], 1), vec![4.0, 5.5, 6.0]);
+        assert!(chunk_averages(&[1.0, 2.0], 0).is_empty());
+    }
+
+    #[test]
+    fn test_rle_round_trip() {
+        let letters: Vec<char> = "aaabccdddd".chars().collect();
+        let encoded = rle_encode(&letters);
+        assert_eq!(encoded, vec![('a', 3), ('b', 1), ('c', 2), ('d', 4)]);
+        assert_eq!(rle_decode(&encoded), letters);
+
+        let nums = [7, 7, 0, 0, 0, 7];
+        let encoded = rle_encode(&nums);
+        assert_eq!(encoded, vec![(7, 2), (0, 3), (7, 1)]);
+        assert_eq!(rle_decode(&encoded), nums.to_vec());
+    }
+
+    #[test]
+    fn test_rle_edge_cases() {
+        let empty: [i32; 0] = [];
+        assert!(rle_encode(&empty).is_empty());
+
+        // No runs: every count is 1
+        assert_eq!(rle_encode(&[1, 2, 3]), vec![(1, 1), (2, 1), (3, 1)]);
+    }
+
+    #[test]
+    fn test_parallel_word_count_matches_sequential() {
+        let texts = vec![
+            String::from("one two three"),
+            String::from("two three"),
+            String::from("three"),
+            String::from(""),
+        ];
+        let sequential = word_frequency(&texts.join(" "));
+        let parallel = parallel_word_count(texts);
+
+        assert_eq!(parallel, sequential);
+        assert_eq!(parallel.get("three"), Some(&3));
+        assert_eq!(parallel.get("one"), Some(&1));
+    }
+
+    #[test]
+    fn test_parallel_word_count_empty() {
+        assert!(parallel_word_count(vec![]).is_empty());
+        assert_eq!(parallel_word_count(vec![String::new()]), word_frequency(""));
+    }
+
+    #[test]
+    fn test_strict_zip() {
+        assert_eq!(
+            strict_zip(&[1, 2, 3], &['a', 'b', 'c']),
+            Ok(vec![(1, 'a'), (2, 'b'), (3, 'c')])
+        );
+
+        let empty: [i32; 0] = [];
+        assert_eq!(strict_zip(&empty, &empty), Ok(vec![]));
+
+        assert_eq!(
+            strict_zip(&[1, 2, 3], &['a', 'b']),
+            Err(String::from(
+                "Length mismatch: first slice has 3 elements, second has 2"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_argmax_argmin() {
+        let nums = [3, 9, 1, 7];
+        assert_eq!(argmax(&nums), Some((1, 9)));
+        assert_eq!(argmin(&nums), Some((2, 1)));
+
+        let floats = [2.5, -1.0, 4.0];
+        assert_eq!(argmax(&floats), Some((2, 4.0)));
+        assert_eq!(argmin(&floats), Some((1, -1.0)));
+    }
+
+    #[test]
+    fn test_argmax_argmin_ties_and_edges() {
+        // Ties at the start and end: the earliest index wins
+        let nums = [5, 1, 3, 5, 1];
+        assert_eq!(argmax(&nums), Some((0, 5)));
+        assert_eq!(argmin(&nums), Some((1, 1)));
+
+        assert_eq!(argmax(&[42]), Some((0, 42)));
+        assert_eq!(argmin(&[42]), Some((0, 42)));
+
+        let empty: [i32; 0] = [];
+        assert_eq!(argmax(&empty), None);
+        assert_eq!(argmin(&empty), None);
+    }
+
+    #[test]
+    fn test_dedup_keep_last() {
+        assert_eq!(dedup_keep_last(&[1, 2, 1, 3]), vec![2, 1, 3]);
+        assert_eq!(dedup_keep_last(&["a", "b", "c"]), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_window_apply() {
+        let nums = [1, 5, 2, 8, 3];
+        assert_eq!(
+            window_apply(&nums, 2, |w| w.iter().sum::<i32>()),
+            vec![6, 7, 10, 11]
+        );
+        assert_eq!(
+            window_apply(&nums, 3, |w| *w.iter().max().unwrap()),
+            vec![5, 8, 8]
+        );
+
+        assert!(window_apply(&nums, 0, |w| w.len()).is_empty());
+        assert!(window_apply(&nums, 6, |w| w.len()).is_empty());
+    }
+
+    #[test]
+    fn test_parallel_sum_matches_sequential() {
+        for size in [1, 2, 7, 100, 1001] {
+            let nums: Vec<i64> = (0..size).map(|x| x * 3 - 50).collect();
+            let expected: i64 = nums.iter().sum();
+            for threads in [1, 2, 3, 8, 2000] {
+                assert_eq!(parallel_sum(nums.clone(), threads), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parallel_sum_edge_cases() {
+        assert_eq!(parallel_sum(vec![], 4), 0);
+        // Zero threads falls back to one
+        assert_eq!(parallel_sum(vec![1, 2, 3], 0), 6);
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        assert_eq!(
+            merge_sorted(&[1, 4, 7], &[2, 3, 8, 9]),
+            vec![1, 2, 3, 4, 7, 8, 9]
+        );
+        assert_eq!(
+            merge_sorted(&["apple", "melon"], &["kiwi"]),
+            vec!["apple", "kiwi", "melon"]
+        );
+    }
+
+    #[test]
+    fn test_merge_sorted_empty_inputs() {
+        assert_eq!(merge_sorted(&[], &[1, 2, 3]), vec![1, 2, 3]);
+        assert_eq!(merge_sorted(&[1, 2, 3], &[]), vec![1, 2, 3]);
+        let empty: [i32; 0] = [];
+        assert!(merge_sorted(&empty, &empty).is_empty());
+    }
+
+    #[test]
+    fn test_merge_sorted_duplicates() {
+        assert_eq!(
+            merge_sorted(&[1, 2, 2, 5], &[2, 5, 5]),
+            vec![1, 2, 2, 2, 5, 5, 5]
+        );
+    }
+
+    #[test]
+    fn test_map_to_sorted_vec_string_keys() {
+        let mut map = HashMap::new();
+        map.insert(String::from("pear"), 3);
+        map.insert(String::from("apple"), 1);
+        map.insert(String::from("fig"), 2);
+        assert_eq!(
+            map_to_sorted_vec(&map),
+            vec![
+                (String::from("apple"), 1),
+                (String::from("fig"), 2),
+                (String::from("pear"), 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_map_to_sorted_vec_integer_keys() {
+        let map: HashMap<i32, &str> = [(10, "ten"), (-2, "minus two"), (3, "three")]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            map_to_sorted_vec(&map),
+            vec![(-2, "minus two"), (3, "three"), (10, "ten")]
+        );
+
+        let empty: HashMap<i32, i32> = HashMap::new();
+        assert!(map_to_sorted_vec(&empty).is_empty());
+    }
+
+    #[test]
+    fn test_collect_unique_into_vec() {
+        let unique: Vec<i32> = collect_unique(&[3, 1, 3, 2, 1, 4]);
+        assert_eq!(unique, vec![3, 1, 2, 4]);
+        assert_eq!(
+            collect_unique::<_, Vec<_>>(&["b", "a", "b"]),
+            vec!["b", "a"]
+        );
+        assert_eq!(collect_unique::<i32, ItemCount>(&[]), ItemCount(0));
+    }
+
+    #[test]
+    fn test_collect_unique_into_hashset() {
+        let unique = collect_unique::<_, HashSet<_>>(&['x', 'y', 'x', 'z', 'y']);
+        assert_eq!(unique, HashSet::from(['x', 'y', 'z']));
+        assert_eq!(
+            collect_unique::<_, ItemCount>(&['x', 'y', 'x']),
+            ItemCount(2)
+        );
+    }
+
+    #[test]
+    fn test_markov_chain_next_word() {
+        let chain = MarkovChain::train("the cat sat . the cat ran . the dog sat");
+        assert_eq!(chain.next_word("the"), Some("cat"));
+        assert_eq!(chain.next_word("cat"), Some("ran")); // tie with "sat", alphabetical wins
+        assert_eq!(chain.next_word("dog"), Some("sat"));
+    }
+
+    #[test]
+    fn test_markov_chain_unknown_word() {
+        let chain = MarkovChain::train("hello world hello world");
+        assert_eq!(chain.next_word("hello"), Some("world"));
+        assert_eq!(chain.next_word("goodbye"), None);
+        // The last word of the text has no recorded successor unless it appears earlier
+        assert_eq!(MarkovChain::train("only").next_word("only"), None);
+    }
+
+    #[test]
+    fn test_rolling_min() {
+        assert_eq!(rolling_min(&[5, 3, 8, 2, 7, 6], 3), vec![3, 2, 2, 2]);
+        assert_eq!(rolling_min(&[4, -1, 9], 3), vec![-1]);
+        assert_eq!(rolling_min(&[4, -1, 9], 1), vec![4, -1, 9]);
+    }
+
+    #[test]
+    fn test_rolling_min_invalid_window() {
+        assert!(rolling_min(&[1, 2, 3], 0).is_empty());
+        assert!(rolling_min(&[1, 2, 3], 4).is_empty());
+        assert!(rolling_min(&[], 1).is_empty());
+    }
+
+    #[test]
+    fn test_ema() {
+        assert_eq!(ema(&[10.0, 20.0, 30.0], 0.5), Ok(vec![10.0, 15.0, 22.5]));
+        // alpha 1 just follows the input
+        assert_eq!(ema(&[3.0, -1.0, 4.0], 1.0), Ok(vec![3.0, -1.0, 4.0]));
+
+        let smoothed = ema(&[100.0, 0.0, 0.0], 0.1).unwrap();
+        assert!((smoothed[1] - 90.0).abs() < 1e-9);
+        assert!((smoothed[2] - 81.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ema_invalid_alpha_and_empty() {
+        assert!(ema(&[1.0], 0.0).is_err());
+        assert!(ema(&[1.0], 1.5).is_err());
+        assert!(ema(&[1.0], f64::NAN).is_err());
+        assert_eq!(ema(&[], 0.3), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_intersect_all() {
+        let sets = [
+            HashSet::from([1, 2, 3, 4, 5]),
+            HashSet::from([2, 3, 5, 7]),
+            HashSet::from([0, 3, 5, 2]),
+        ];
+        assert_eq!(intersect_all(&sets), HashSet::from([2, 3, 5]));
+
+        let disjoint = [
+            HashSet::from([1, 2]),
+            HashSet::from([2, 3]),
+            HashSet::from([3, 4]),
+        ];
+        assert!(intersect_all(&disjoint).is_empty());
+    }
+
+    #[test]
+    fn test_intersect_all_single_and_empty() {
+        let single = [HashSet::from([8, 6, 7])];
+        assert_eq!(intersect_all(&single), HashSet::from([6, 7, 8]));
+        assert!(intersect_all(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_counter_take() {
+        assert_eq!(
+            Counter::new(0, 1).take(4).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(
+            Counter::new(5, 0).take(3).collect::<Vec<_>>(),
+            vec![5, 5, 5]
+        );
+    }
+
+    #[test]
+    fn test_counter_step() {
+        assert_eq!(
+            Counter::new(10, 5).take(4).collect::<Vec<_>>(),
+            vec![10, 15, 20, 25]
+        );
+        // Stops rather than overflowing
+        assert_eq!(
+            Counter::new(u64::MAX - 1, 1).collect::<Vec<_>>(),
+            vec![u64::MAX - 1, u64::MAX]
+        );
+    }
+
+    #[test]
+    fn test_counter_adapters() {
+        let sum_of_squares: u64 = Counter::new(1, 1).take(4).map(|n| n * n).sum();
+        assert_eq!(sum_of_squares, 30);
+
+        let evens: Vec<u64> = Counter::new(1, 3).filter(|n| n % 2 == 0).take(3).collect();
+        assert_eq!(evens, vec![4, 10, 16]);
+    }
+
+    #[test]
+    fn test_diff_changes() {
+        assert_eq!(diff(&[1, 2, 3], &[1, 9, 3]), vec![(1, Some(2), Some(9))]);
+        assert_eq!(
+            diff(&['a', 'b'], &['a', 'b', 'c']),
+            vec![(2, None, Some('c'))]
+        );
+        assert_eq!(
+            diff(&[1, 2, 3], &[7]),
+            vec![
+                (0, Some(1), Some(7)),
+                (1, Some(2), None),
+                (2, Some(3), None)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_identical() {
+        assert!(diff(&[1, 2, 3], &[1, 2, 3]).is_empty());
+        let empty: [i32; 0] = [];
+        assert!(diff(&empty, &empty).is_empty());
+    }
+
+    #[test]
+    fn test_is_balanced() {
+        assert!(is_balanced("{[()()]}"));
+        assert!(is_balanced("fn main() { let v = vec![(1, 2)]; }"));
+        assert!(is_balanced(""));
+        assert!(is_balanced("no brackets at all"));
+    }
+
+    #[test]
+    fn test_is_unbalanced() {
+        assert!(!is_balanced("(]")); // mismatched closing type
+        assert!(!is_balanced("{[}]")); // interleaved
+        assert!(!is_balanced("([]")); // unclosed opener
+        assert!(!is_balanced("())")); // unexpected closer
+        assert!(!is_balanced(")("));
+    }
 }
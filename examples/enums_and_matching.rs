@@ -1,6 +1,9 @@
 // Enums and Pattern Matching Example
 // Rust enums are much more powerful than in most languages!
 
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
 // 1. SIMPLE ENUM - Like traditional enums
 #[derive(Debug, PartialEq)]
 enum TrafficLight {
@@ -26,23 +29,84 @@ impl TrafficLight {
     }
 }
 
+// Parsing from text can fail, so TryFrom instead of From
+impl TryFrom<&str> for TrafficLight {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim().to_lowercase().as_str() {
+            "red" => Ok(TrafficLight::Red),
+            "yellow" => Ok(TrafficLight::Yellow),
+            "green" => Ok(TrafficLight::Green),
+            _ => Err(format!("Unknown traffic light color: '{}'", value)),
+        }
+    }
+}
+
 // 2. ENUM WITH DATA - Each variant can hold different data!
 #[derive(Debug)]
 enum Message {
-    Quit,                       // No data
-    Move { x: i32, y: i32 },   // Named fields (like a struct)
-    Write(String),              // Single value
-    ChangeColor(u8, u8, u8),   // Tuple values (RGB)
+    Quit,                    // No data
+    Move { x: i32, y: i32 }, // Named fields (like a struct)
+    Write(String),           // Single value
+    ChangeColor(u8, u8, u8), // Tuple values (RGB)
+    Batch(Vec<Message>),     // Recursive: a message made of messages
 }
 
 impl Message {
-    fn process(&self) {
+    // Batches inside batches are fine, but only up to this depth
+    const MAX_BATCH_DEPTH: usize = 8;
+
+    // Returns how many (non-batch) messages were processed
+    fn process(&self) -> usize {
+        self.process_at_depth(0)
+    }
+
+    fn process_at_depth(&self, depth: usize) -> usize {
+        match self {
+            Message::Batch(messages) => {
+                if depth >= Self::MAX_BATCH_DEPTH {
+                    println!("⚠️  Batch nested too deeply, skipping");
+                    return 0;
+                }
+                println!("📦 Processing batch of {} messages", messages.len());
+                messages
+                    .iter()
+                    .map(|message| message.process_at_depth(depth + 1))
+                    .sum()
+            }
+            _ => {
+                println!("{}", self.format_message());
+                1
+            }
+        }
+    }
+
+    fn format_message(&self) -> String {
+        self.format_at_depth(0)
+    }
+
+    fn format_at_depth(&self, depth: usize) -> String {
         match self {
-            Message::Quit => println!("🚪 Quitting application..."),
-            Message::Move { x, y } => println!("📍 Moving to position ({}, {})", x, y),
-            Message::Write(text) => println!("✍️  Writing: {}", text),
+            Message::Quit => "🚪 Quitting application...".to_string(),
+            Message::Move { x, y } => format!("📍 Moving to position ({}, {})", x, y),
+            Message::Write(text) => format!("✍️  Writing: {}", text),
             Message::ChangeColor(r, g, b) => {
-                println!("🎨 Changing color to RGB({}, {}, {})", r, g, b)
+                format!("🎨 Changing color to RGB({}, {}, {})", r, g, b)
+            }
+            Message::Batch(_) if depth >= Self::MAX_BATCH_DEPTH => {
+                "⚠️  Batch nested too deeply".to_string()
+            }
+            Message::Batch(messages) => {
+                // One indented line per sub-message (nested batches indent further)
+                let mut out = format!("📦 Batch of {} messages:", messages.len());
+                for message in messages {
+                    for line in message.format_at_depth(depth + 1).lines() {
+                        out.push_str("\n  ");
+                        out.push_str(line);
+                    }
+                }
+                out
             }
         }
     }
@@ -86,10 +150,20 @@ fn parse_age(input: &str) -> Result<u32, String> {
     }
 }
 
+// Forgiving version: keeps only the digits ("25 years" -> "25") before validating
+fn parse_age_lenient(input: &str) -> Result<u32, String> {
+    let cleaned: String = input.chars().filter(|c| c.is_ascii_digit()).collect();
+    if cleaned.is_empty() {
+        return Err(format!("No digits found in '{}'", input));
+    }
+    parse_age(&cleaned).map_err(|e| format!("{} (did you mean '{}'?)", e, cleaned))
+}
+
 // 5. COMPLEX ENUM - Representing different types of data
 #[derive(Debug)]
 enum WebEvent {
     PageLoad,
+    #[allow(dead_code)] // Handled below, but no demo event unloads a page
     PageUnload,
     KeyPress(char),
     Paste(String),
@@ -106,6 +180,22 @@ fn handle_event(event: WebEvent) {
     }
 }
 
+// Tally how many times each kind of event occurred
+fn count_event_types(events: &[WebEvent]) -> HashMap<&'static str, usize> {
+    let mut counts = HashMap::new();
+    for event in events {
+        let name = match event {
+            WebEvent::PageLoad => "PageLoad",
+            WebEvent::PageUnload => "PageUnload",
+            WebEvent::KeyPress(_) => "KeyPress",
+            WebEvent::Paste(_) => "Paste",
+            WebEvent::Click { .. } => "Click",
+        };
+        *counts.entry(name).or_insert(0) += 1;
+    }
+    counts
+}
+
 // 6. ENUM FOR STATE MACHINE
 #[derive(Debug, PartialEq)]
 enum ConnectionState {
@@ -134,15 +224,250 @@ impl ConnectionState {
     }
 }
 
+// Drives a ConnectionState through its transitions and keeps a log
+struct ConnectionManager {
+    state: ConnectionState,
+    log: Vec<String>,
+}
+
+impl ConnectionManager {
+    const BASE_DELAY_MS: u32 = 100;
+
+    fn new() -> Self {
+        ConnectionManager {
+            state: ConnectionState::Disconnected,
+            log: Vec::new(),
+        }
+    }
+
+    // Simulated retries: attempts up to fail_until fail, the next one succeeds.
+    // The delay doubles after each failure (logged, not actually slept).
+    fn connect_with_backoff(&mut self, max_attempts: u32, fail_until: u32) -> Result<(), String> {
+        let mut delay_ms = Self::BASE_DELAY_MS;
+        for attempt in 1..=max_attempts {
+            self.state = ConnectionState::Connecting;
+            if attempt > fail_until {
+                self.log.push(format!("attempt {}: connected", attempt));
+                self.state = ConnectionState::Connected {
+                    session_id: format!("session-{}", attempt),
+                };
+                return Ok(());
+            }
+            self.log.push(format!(
+                "attempt {}: failed, retrying in {}ms",
+                attempt, delay_ms
+            ));
+            delay_ms = delay_ms.saturating_mul(2);
+        }
+
+        let message = format!("Failed to connect after {} attempts", max_attempts);
+        self.state = ConnectionState::Error {
+            code: 503,
+            message: message.clone(),
+        };
+        Err(message)
+    }
+}
+
+// TYPE-STATE PATTERN - The state lives in the type, so the compiler rejects
+// illegal transitions instead of checking them at runtime. Markers are zero-sized.
+struct Disconnected;
+struct Connecting;
+struct Connected;
+
+struct Connection<S> {
+    host: String,
+    session_id: Option<String>, // Always Some in the Connected state
+    _state: PhantomData<S>,
+}
+
+// Available in every state
+impl<S> Connection<S> {
+    fn host(&self) -> &str {
+        &self.host
+    }
+
+    fn into_state<T>(self) -> Connection<T> {
+        Connection {
+            host: self.host,
+            session_id: self.session_id,
+            _state: PhantomData,
+        }
+    }
+}
+
+impl Connection<Disconnected> {
+    fn new(host: &str) -> Self {
+        Connection {
+            host: host.to_string(),
+            session_id: None,
+            _state: PhantomData,
+        }
+    }
+
+    fn connect(self) -> Connection<Connecting> {
+        self.into_state()
+    }
+}
+
+impl Connection<Connecting> {
+    fn established(mut self, session_id: &str) -> Connection<Connected> {
+        self.session_id = Some(session_id.to_string());
+        self.into_state()
+    }
+
+    fn abort(self) -> Connection<Disconnected> {
+        self.into_state()
+    }
+}
+
+impl Connection<Connected> {
+    fn session_id(&self) -> &str {
+        self.session_id
+            .as_deref()
+            .expect("a Connected connection always has a session")
+    }
+
+    fn send(&self, message: &str) -> String {
+        format!("[{}@{}] {}", self.session_id(), self.host, message)
+    }
+
+    fn disconnect(mut self) -> Connection<Disconnected> {
+        self.session_id = None;
+        self.into_state()
+    }
+}
+
+// None of these compile - the methods simply don't exist for that state:
+//   Connection::new("db").send("hi");            // not connected yet
+//   Connection::new("db").established("abc");    // must connect() first
+//   Connection::new("db").connect().connect();   // already connecting
+
+// Another runtime state machine: every method matches on the current state
+#[derive(Debug, PartialEq)]
+enum VendingState {
+    Idle,
+    Collecting { inserted: u32 }, // cents inserted so far
+    Dispensing,                   // waiting for the customer to take the item
+}
+
+struct VendingMachine {
+    state: VendingState,
+}
+
+impl VendingMachine {
+    fn new() -> Self {
+        VendingMachine {
+            state: VendingState::Idle,
+        }
+    }
+
+    fn insert_coin(&mut self, cents: u32) -> Result<(), String> {
+        self.state = match self.state {
+            VendingState::Idle => VendingState::Collecting { inserted: cents },
+            VendingState::Collecting { inserted } => VendingState::Collecting {
+                inserted: inserted
+                    .checked_add(cents)
+                    .ok_or_else(|| "Too many coins inserted".to_string())?,
+            },
+            VendingState::Dispensing => return Err("Please take your item first".to_string()),
+        };
+        Ok(())
+    }
+
+    // Returns the change; an underfunded selection keeps the coins collected so far
+    fn select_item(&mut self, price: u32) -> Result<u32, String> {
+        match self.state {
+            VendingState::Idle => Err("Insert coins first".to_string()),
+            VendingState::Collecting { inserted } if inserted < price => Err(format!(
+                "Insufficient funds: inserted {}, price {}",
+                inserted, price
+            )),
+            VendingState::Collecting { inserted } => {
+                self.state = VendingState::Dispensing;
+                Ok(inserted - price)
+            }
+            VendingState::Dispensing => Err("Already dispensing".to_string()),
+        }
+    }
+
+    fn take_item(&mut self) {
+        if self.state == VendingState::Dispensing {
+            self.state = VendingState::Idle;
+        }
+    }
+
+    // Refunds whatever has been inserted (nothing once an item is dispensing)
+    fn cancel(&mut self) -> u32 {
+        match self.state {
+            VendingState::Collecting { inserted } => {
+                self.state = VendingState::Idle;
+                inserted
+            }
+            VendingState::Idle | VendingState::Dispensing => 0,
+        }
+    }
+}
+
 // 7. NESTED ENUMS
 #[derive(Debug)]
 enum Shape {
     Circle(f64),                              // radius
     Rectangle { width: f64, height: f64 },
-    Triangle(f64, f64, f64),                 // three sides
+    Triangle(f64, f64, f64), // three sides
+    Composite(Vec<Shape>),   // made of other shapes, possibly composites
 }
 
 impl Shape {
+    // try_composite refuses to nest composites deeper than this
+    const MAX_COMPOSITE_DEPTH: usize = 16;
+
+    // Validating constructor: sides must be finite, positive and obey the triangle inequality
+    fn try_new_triangle(a: f64, b: f64, c: f64) -> Result<Shape, String> {
+        // Checked first: every comparison with NaN is false, so it would slip past the rest
+        if !a.is_finite() || !b.is_finite() || !c.is_finite() {
+            return Err(format!(
+                "Triangle sides must be finite, got ({}, {}, {})",
+                a, b, c
+            ));
+        }
+        if a <= 0.0 || b <= 0.0 || c <= 0.0 {
+            return Err(format!(
+                "Triangle sides must be positive, got ({}, {}, {})",
+                a, b, c
+            ));
+        }
+        if a + b <= c || a + c <= b || b + c <= a {
+            return Err(format!(
+                "Sides ({}, {}, {}) cannot form a triangle",
+                a, b, c
+            ));
+        }
+        Ok(Shape::Triangle(a, b, c))
+    }
+
+    // Validating constructor: keeps nesting within MAX_COMPOSITE_DEPTH so recursion stays shallow
+    fn try_composite(children: Vec<Shape>) -> Result<Shape, String> {
+        let composite = Shape::Composite(children);
+        if composite.nesting_depth() > Self::MAX_COMPOSITE_DEPTH {
+            return Err(format!(
+                "Composite shapes can be nested at most {} levels deep",
+                Self::MAX_COMPOSITE_DEPTH
+            ));
+        }
+        Ok(composite)
+    }
+
+    // How many composites deep the innermost shape sits; plain shapes are 0
+    fn nesting_depth(&self) -> usize {
+        match self {
+            Shape::Composite(children) => {
+                1 + children.iter().map(Shape::nesting_depth).max().unwrap_or(0)
+            }
+            _ => 0,
+        }
+    }
+
     fn area(&self) -> f64 {
         match self {
             Shape::Circle(radius) => std::f64::consts::PI * radius * radius,
@@ -152,6 +477,84 @@ impl Shape {
                 let s = (a + b + c) / 2.0;
                 (s * (s - a) * (s - b) * (s - c)).sqrt()
             }
+            // fold from 0.0 so an empty composite is 0.0 rather than -0.0
+            Shape::Composite(children) => children
+                .iter()
+                .fold(0.0, |total, child| total + child.area()),
+        }
+    }
+
+    // Hand-built JSON; {:?} keeps the ".0" on whole floats (5.0, not 5)
+    fn to_json(&self) -> String {
+        match self {
+            Shape::Circle(radius) => format!(r#"{{"type":"circle","radius":{:?}}}"#, radius),
+            Shape::Rectangle { width, height } => format!(
+                r#"{{"type":"rectangle","width":{:?},"height":{:?}}}"#,
+                width, height
+            ),
+            Shape::Triangle(a, b, c) => {
+                format!(r#"{{"type":"triangle","sides":[{:?},{:?},{:?}]}}"#, a, b, c)
+            }
+            Shape::Composite(children) => {
+                let parts: Vec<String> = children.iter().map(|child| child.to_json()).collect();
+                format!(r#"{{"type":"composite","shapes":[{}]}}"#, parts.join(","))
+            }
+        }
+    }
+
+    // Returns a new shape with every linear dimension multiplied by factor
+    fn scale(&self, factor: f64) -> Result<Shape, String> {
+        if factor <= 0.0 || factor.is_nan() {
+            return Err(format!("Scale factor must be positive, got {}", factor));
+        }
+        let scaled = match self {
+            Shape::Circle(radius) => Shape::Circle(radius * factor),
+            Shape::Rectangle { width, height } => Shape::Rectangle {
+                width: width * factor,
+                height: height * factor,
+            },
+            Shape::Triangle(a, b, c) => Shape::Triangle(a * factor, b * factor, c * factor),
+            Shape::Composite(children) => Shape::Composite(
+                children
+                    .iter()
+                    .map(|child| child.scale(factor))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        };
+        Ok(scaled)
+    }
+}
+
+// Each example is its own binary, so this mirrors the trait of the same name in
+// structs_and_methods.rs rather than sharing it
+trait Serializable {
+    // (field name, value) pairs; the variant is reported under "type"
+    fn to_pairs(&self) -> Vec<(String, String)>;
+}
+
+impl Serializable for Shape {
+    fn to_pairs(&self) -> Vec<(String, String)> {
+        let pair = |key: &str, value: String| (key.to_string(), value);
+        match self {
+            Shape::Circle(radius) => vec![
+                pair("type", String::from("circle")),
+                pair("radius", radius.to_string()),
+            ],
+            Shape::Rectangle { width, height } => vec![
+                pair("type", String::from("rectangle")),
+                pair("width", width.to_string()),
+                pair("height", height.to_string()),
+            ],
+            Shape::Triangle(a, b, c) => vec![
+                pair("type", String::from("triangle")),
+                pair("side_a", a.to_string()),
+                pair("side_b", b.to_string()),
+                pair("side_c", c.to_string()),
+            ],
+            Shape::Composite(children) => vec![
+                pair("type", String::from("composite")),
+                pair("children", children.len().to_string()),
+            ],
         }
     }
 }
@@ -164,7 +567,16 @@ fn main() {
     let light = TrafficLight::Red;
     println!("Light: {:?}", light);
     println!("Can go? {}", light.can_go());
-    println!("Time to change: {} seconds\n", light.time_to_change());
+    println!("Time to change: {} seconds", light.time_to_change());
+
+    // Parsing from config text
+    for text in ["GREEN", "blue"] {
+        match TrafficLight::try_from(text) {
+            Ok(light) => println!("Parsed '{}' as {:?}", text, light),
+            Err(e) => println!("❌ {}", e),
+        }
+    }
+    println!();
 
     // 2. Enum with Data
     println!("--- Messages ---");
@@ -178,6 +590,13 @@ fn main() {
     for msg in messages {
         msg.process();
     }
+
+    let batch = Message::Batch(vec![
+        Message::Write(String::from("batched")),
+        Message::Move { x: 1, y: 2 },
+    ]);
+    println!("{}", batch.format_message());
+    println!("Processed {} messages from the batch", batch.process());
     println!();
 
     // 3. Option Enum
@@ -221,6 +640,14 @@ fn main() {
             Err(e) => println!("❌ Error parsing '{}': {}", age_str, e),
         }
     }
+
+    // Lenient parsing strips the extra text first
+    for age_str in ["25 years old", "age: 30", "unknown", "150 years"] {
+        match parse_age_lenient(age_str) {
+            Ok(age) => println!("✅ Lenient age from '{}': {}", age_str, age),
+            Err(e) => println!("❌ Lenient error for '{}': {}", age_str, e),
+        }
+    }
     println!();
 
     // 6. Web Events
@@ -232,6 +659,8 @@ fn main() {
         WebEvent::Click { x: 100, y: 200 },
     ];
 
+    println!("Event counts: {:?}", count_event_types(&events));
+
     for event in events {
         handle_event(event);
     }
@@ -254,6 +683,40 @@ fn main() {
     for state in &states {
         println!("{} - Connected: {}", state.describe(), state.is_connected());
     }
+
+    // Retrying with exponential backoff
+    let mut manager = ConnectionManager::new();
+    let outcome = manager.connect_with_backoff(5, 2);
+    for entry in &manager.log {
+        println!("  {}", entry);
+    }
+    println!("Outcome: {:?} - {}", outcome, manager.state.describe());
+
+    // Type-state: each step returns a connection in the next state
+    let conn = Connection::new("db.example.com").connect();
+    println!("Connecting to {}...", conn.host());
+    let conn = conn.established("xyz789");
+    println!("{}", conn.send("SELECT 1"));
+    let conn = conn.disconnect();
+    println!("Disconnected from {}", conn.host());
+    let conn = conn.connect().abort();
+    println!("Gave up connecting to {}", conn.host());
+
+    // Vending machine
+    let mut machine = VendingMachine::new();
+    println!("Select before paying: {:?}", machine.select_item(125));
+    for coin in [100, 25, 25] {
+        machine.insert_coin(coin).expect("Machine is collecting");
+    }
+    println!("Inserted coins, state: {:?}", machine.state);
+    println!(
+        "Buying a 125 cent snack: {:?} change",
+        machine.select_item(125)
+    );
+    println!("Coin while dispensing: {:?}", machine.insert_coin(10));
+    machine.take_item();
+    machine.insert_coin(50).expect("Machine is idle again");
+    println!("Cancelled, refunded {} cents", machine.cancel());
     println!();
 
     // 8. Shapes
@@ -262,10 +725,31 @@ fn main() {
         Shape::Circle(5.0),
         Shape::Rectangle { width: 4.0, height: 6.0 },
         Shape::Triangle(3.0, 4.0, 5.0),
+        Shape::try_composite(vec![
+            Shape::Circle(1.0),
+            Shape::Rectangle {
+                width: 2.0,
+                height: 3.0,
+            },
+        ])
+        .expect("One level of nesting is within the limit"),
     ];
 
     for shape in shapes {
         println!("{:?} - Area: {:.2}", shape, shape.area());
+        println!("  JSON: {}", shape.to_json());
+        println!("  Pairs: {:?}", shape.to_pairs());
+    }
+
+    match Shape::Circle(5.0).scale(2.0) {
+        Ok(bigger) => println!("Scaled x2: {:?} - Area: {:.2}", bigger, bigger.area()),
+        Err(e) => println!("❌ {}", e),
+    }
+    if let Err(e) = Shape::Circle(5.0).scale(-1.0) {
+        println!("❌ {}", e);
+    }
+    if let Err(e) = Shape::try_new_triangle(1.0, 2.0, 3.0) {
+        println!("❌ {}", e);
     }
     println!();
 
@@ -273,6 +757,7 @@ fn main() {
     println!("--- Advanced Patterns ---");
     let number = Some(7);
 
+    #[allow(clippy::redundant_guards)] // `Some(7)` would do, but this shows a guard
     match number {
         Some(x) if x < 5 => println!("Less than 5: {}", x),
         Some(x) if x == 7 => println!("Lucky number 7!"),
@@ -307,6 +792,17 @@ mod tests {
         assert_eq!(green.can_go(), true);
     }
 
+    #[test]
+    fn test_traffic_light_try_from() {
+        assert_eq!(TrafficLight::try_from("red"), Ok(TrafficLight::Red));
+        assert_eq!(TrafficLight::try_from("YeLLoW"), Ok(TrafficLight::Yellow));
+        assert_eq!(TrafficLight::try_from("Green"), Ok(TrafficLight::Green));
+        assert_eq!(
+            TrafficLight::try_from("purple"),
+            Err("Unknown traffic light color: 'purple'".to_string())
+        );
+    }
+
     #[test]
     fn test_divide() {
         assert_eq!(divide(10, 2), Some(5));
@@ -339,4 +835,341 @@ mod tests {
         let rect = Shape::Rectangle { width: 4.0, height: 5.0 };
         assert_eq!(rect.area(), 20.0);
     }
+
+    #[test]
+    fn test_vending_purchase_with_change() {
+        let mut machine = VendingMachine::new();
+        machine.insert_coin(100).unwrap();
+        machine.insert_coin(50).unwrap();
+        assert_eq!(machine.state, VendingState::Collecting { inserted: 150 });
+        assert_eq!(machine.select_item(120), Ok(30));
+        assert_eq!(machine.state, VendingState::Dispensing);
+
+        assert!(machine.insert_coin(25).is_err());
+        machine.take_item();
+        assert_eq!(machine.state, VendingState::Idle);
+    }
+
+    #[test]
+    fn test_vending_underfunded() {
+        let mut machine = VendingMachine::new();
+        assert_eq!(
+            machine.select_item(100),
+            Err("Insert coins first".to_string())
+        );
+
+        machine.insert_coin(75).unwrap();
+        assert_eq!(
+            machine.select_item(100),
+            Err("Insufficient funds: inserted 75, price 100".to_string())
+        );
+        // Topping up after a failed selection works
+        machine.insert_coin(25).unwrap();
+        assert_eq!(machine.select_item(100), Ok(0));
+    }
+
+    #[test]
+    fn test_vending_cancel() {
+        let mut machine = VendingMachine::new();
+        assert_eq!(machine.cancel(), 0);
+
+        machine.insert_coin(25).unwrap();
+        machine.insert_coin(10).unwrap();
+        assert_eq!(machine.cancel(), 35);
+        assert_eq!(machine.state, VendingState::Idle);
+        assert_eq!(machine.cancel(), 0);
+    }
+
+    #[test]
+    fn test_vending_coin_overflow() {
+        let mut machine = VendingMachine::new();
+        machine.insert_coin(u32::MAX).unwrap();
+        assert_eq!(
+            machine.insert_coin(1),
+            Err("Too many coins inserted".to_string())
+        );
+        // The coins already collected are kept
+        assert_eq!(
+            machine.state,
+            VendingState::Collecting { inserted: u32::MAX }
+        );
+    }
+
+    #[test]
+    fn test_shape_to_pairs() {
+        let pairs = |items: &[(&str, &str)]| -> Vec<(String, String)> {
+            items
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+        assert_eq!(
+            Shape::Rectangle {
+                width: 4.0,
+                height: 6.5
+            }
+            .to_pairs(),
+            pairs(&[("type", "rectangle"), ("width", "4"), ("height", "6.5")])
+        );
+        assert_eq!(
+            Shape::Triangle(3.0, 4.0, 5.0).to_pairs(),
+            pairs(&[
+                ("type", "triangle"),
+                ("side_a", "3"),
+                ("side_b", "4"),
+                ("side_c", "5")
+            ])
+        );
+        assert_eq!(
+            Shape::Composite(vec![Shape::Circle(1.0)]).to_pairs(),
+            pairs(&[("type", "composite"), ("children", "1")])
+        );
+    }
+
+    #[test]
+    fn test_composite_area() {
+        let composite = Shape::Composite(vec![
+            Shape::Circle(1.0),
+            Shape::Rectangle {
+                width: 4.0,
+                height: 5.0,
+            },
+        ]);
+        assert!((composite.area() - (std::f64::consts::PI + 20.0)).abs() < 0.0001);
+
+        let nested = Shape::Composite(vec![composite, Shape::Triangle(3.0, 4.0, 5.0)]);
+        assert!((nested.area() - (std::f64::consts::PI + 26.0)).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_empty_composite_area() {
+        let empty = Shape::Composite(vec![]);
+        assert_eq!(empty.area(), 0.0);
+        assert!(empty.area().is_sign_positive());
+    }
+
+    #[test]
+    fn test_composite_depth_limit() {
+        let mut shape = Shape::Rectangle {
+            width: 1.0,
+            height: 1.0,
+        };
+        for _ in 0..Shape::MAX_COMPOSITE_DEPTH {
+            shape = Shape::try_composite(vec![shape]).unwrap();
+        }
+        assert_eq!(shape.nesting_depth(), 16);
+        assert_eq!(shape.area(), 1.0);
+
+        // One more level puts the innermost shape past the limit
+        assert_eq!(
+            Shape::try_composite(vec![Shape::Circle(1.0), shape]).unwrap_err(),
+            "Composite shapes can be nested at most 16 levels deep"
+        );
+        assert!(Shape::try_composite(vec![]).is_ok());
+    }
+
+    #[test]
+    fn test_count_event_types() {
+        let events = vec![
+            WebEvent::PageLoad,
+            WebEvent::KeyPress('a'),
+            WebEvent::KeyPress('b'),
+            WebEvent::Click { x: 1, y: 2 },
+            WebEvent::KeyPress('c'),
+            WebEvent::PageLoad,
+        ];
+        let counts = count_event_types(&events);
+        assert_eq!(counts.get("KeyPress"), Some(&3));
+        assert_eq!(counts.get("PageLoad"), Some(&2));
+        assert_eq!(counts.get("Click"), Some(&1));
+        assert_eq!(counts.get("Paste"), None);
+        assert_eq!(counts.len(), 3);
+
+        assert!(count_event_types(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_shape_scale() {
+        let circle = Shape::Circle(2.0);
+        let scaled = circle.scale(3.0).unwrap();
+        // Area grows by factor squared
+        assert!((scaled.area() - circle.area() * 9.0).abs() < 0.0001);
+
+        let rect = Shape::Rectangle {
+            width: 2.0,
+            height: 5.0,
+        }
+        .scale(0.5)
+        .unwrap();
+        assert_eq!(rect.area(), 2.5);
+
+        let triangle = Shape::Triangle(3.0, 4.0, 5.0).scale(2.0).unwrap();
+        assert!((triangle.area() - 24.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_shape_scale_rejects_non_positive() {
+        assert!(Shape::Circle(1.0).scale(0.0).is_err());
+        assert!(Shape::Circle(1.0).scale(-2.0).is_err());
+    }
+
+    #[test]
+    fn test_parse_age_lenient() {
+        assert_eq!(parse_age_lenient("25 years old"), Ok(25));
+        assert_eq!(parse_age_lenient("age: 30"), Ok(30));
+
+        // Strict parsing is unchanged
+        assert!(parse_age("25 years old").is_err());
+    }
+
+    #[test]
+    fn test_parse_age_lenient_errors() {
+        assert_eq!(
+            parse_age_lenient("unknown"),
+            Err("No digits found in 'unknown'".to_string())
+        );
+
+        // Cleaned value is still range-checked and suggested back
+        assert_eq!(
+            parse_age_lenient("150 years"),
+            Err("Age seems unrealistic (did you mean '150'?)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_try_new_triangle() {
+        let triangle = Shape::try_new_triangle(3.0, 4.0, 5.0).unwrap();
+        assert!((triangle.area() - 6.0).abs() < 0.0001);
+
+        // Degenerate: 1 + 2 == 3
+        assert_eq!(
+            Shape::try_new_triangle(1.0, 2.0, 3.0).unwrap_err(),
+            "Sides (1, 2, 3) cannot form a triangle"
+        );
+
+        assert_eq!(
+            Shape::try_new_triangle(-3.0, 4.0, 5.0).unwrap_err(),
+            "Triangle sides must be positive, got (-3, 4, 5)"
+        );
+    }
+
+    #[test]
+    fn test_try_new_triangle_non_finite() {
+        assert_eq!(
+            Shape::try_new_triangle(f64::NAN, 1.0, 1.0).unwrap_err(),
+            "Triangle sides must be finite, got (NaN, 1, 1)"
+        );
+        assert!(Shape::try_new_triangle(1.0, f64::INFINITY, 1.0).is_err());
+        assert!(Shape::try_new_triangle(1.0, 1.0, f64::NEG_INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_connect_with_backoff_success() {
+        let mut manager = ConnectionManager::new();
+        assert_eq!(manager.connect_with_backoff(5, 2), Ok(()));
+        assert!(manager.state.is_connected());
+        assert_eq!(
+            manager.log,
+            vec![
+                "attempt 1: failed, retrying in 100ms",
+                "attempt 2: failed, retrying in 200ms",
+                "attempt 3: connected",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_connect_with_backoff_exhausted() {
+        let mut manager = ConnectionManager::new();
+        assert_eq!(
+            manager.connect_with_backoff(3, 10),
+            Err("Failed to connect after 3 attempts".to_string())
+        );
+        assert_eq!(manager.log.len(), 3);
+        assert!(!manager.state.is_connected());
+        assert!(matches!(
+            manager.state,
+            ConnectionState::Error { code: 503, .. }
+        ));
+    }
+
+    #[test]
+    fn test_shape_to_json() {
+        assert_eq!(
+            Shape::Circle(5.0).to_json(),
+            r#"{"type":"circle","radius":5.0}"#
+        );
+        assert_eq!(
+            Shape::Rectangle {
+                width: 4.0,
+                height: 6.5
+            }
+            .to_json(),
+            r#"{"type":"rectangle","width":4.0,"height":6.5}"#
+        );
+        assert_eq!(
+            Shape::Triangle(3.0, 4.0, 5.0).to_json(),
+            r#"{"type":"triangle","sides":[3.0,4.0,5.0]}"#
+        );
+    }
+
+    #[test]
+    fn test_typestate_happy_path() {
+        let conn = Connection::new("localhost");
+        assert_eq!(conn.host(), "localhost");
+
+        let conn = conn.connect().established("s1");
+        assert_eq!(conn.session_id(), "s1");
+        assert_eq!(conn.send("ping"), "[s1@localhost] ping");
+
+        // Back to Disconnected, and round again
+        let conn = conn.disconnect();
+        assert!(conn.session_id.is_none());
+        let conn = conn.connect().established("s2");
+        assert_eq!(conn.session_id(), "s2");
+    }
+
+    #[test]
+    fn test_typestate_abort() {
+        let conn = Connection::new("localhost").connect().abort();
+        assert!(conn.session_id.is_none());
+        assert_eq!(conn.host(), "localhost");
+    }
+
+    #[test]
+    fn test_message_batch_processes_all() {
+        let batch = Message::Batch(vec![
+            Message::Move { x: 1, y: 2 },
+            Message::Write(String::from("hi")),
+            Message::ChangeColor(0, 128, 255),
+        ]);
+        assert_eq!(batch.process(), 3);
+
+        let nested = Message::Batch(vec![batch, Message::Quit]);
+        assert_eq!(nested.process(), 4);
+    }
+
+    #[test]
+    fn test_message_batch_format() {
+        let batch = Message::Batch(vec![
+            Message::Move { x: 1, y: 2 },
+            Message::Write(String::from("hi")),
+            Message::ChangeColor(0, 128, 255),
+        ]);
+        assert_eq!(
+            batch.format_message(),
+            "📦 Batch of 3 messages:\n  📍 Moving to position (1, 2)\n  ✍️  Writing: hi\n  🎨 Changing color to RGB(0, 128, 255)"
+        );
+    }
+
+    #[test]
+    fn test_message_batch_depth_limit() {
+        let mut message = Message::Write(String::from("deep"));
+        for _ in 0..20 {
+            message = Message::Batch(vec![message]);
+        }
+        // The innermost message is beyond the limit and never reached
+        assert_eq!(message.process(), 0);
+        assert!(message.format_message().contains("nested too deeply"));
+    }
 }
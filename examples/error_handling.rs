@@ -1,9 +1,12 @@
 // Error Handling in Rust
 // Learn how to handle errors properly without panicking!
 
+use std::env;
+use std::fmt::{self, Display};
 use std::fs::File;
 use std::io::{self, Read, Write};
 use std::num::ParseIntError;
+use std::str::FromStr;
 
 // 1. BASIC RESULT TYPE
 fn divide(a: f64, b: f64) -> Result<f64, String> {
@@ -19,6 +22,8 @@ fn divide(a: f64, b: f64) -> Result<f64, String> {
 enum MathError {
     DivisionByZero,
     NegativeSquareRoot,
+    EmptyInput,
+    #[allow(dead_code)] // Not produced by these examples, but part of the API
     Overflow,
 }
 
@@ -38,6 +43,12 @@ fn square_root(x: f64) -> Result<f64, MathError> {
     }
 }
 
+// ((a / b) / c) / ...; a zero divisor stops the fold (a leading zero is just 0 / b)
+fn divide_sequence(nums: &[f64]) -> Result<f64, MathError> {
+    let (&first, rest) = nums.split_first().ok_or(MathError::EmptyInput)?;
+    rest.iter().try_fold(first, |acc, &n| safe_divide(acc, n))
+}
+
 // 3. THE ? OPERATOR - Propagate errors easily
 fn read_username_from_file(filename: &str) -> Result<String, io::Error> {
     let mut file = File::open(filename)?; // ? returns error if it fails
@@ -47,6 +58,8 @@ fn read_username_from_file(filename: &str) -> Result<String, io::Error> {
 }
 
 // Without ? operator (for comparison)
+#[allow(dead_code)] // Not called from main; it's here to compare against the ? version
+#[allow(clippy::question_mark)] // Deliberately verbose to show what ? does
 fn read_username_verbose(filename: &str) -> Result<String, io::Error> {
     let file_result = File::open(filename);
     let mut file = match file_result {
@@ -63,6 +76,8 @@ fn read_username_verbose(filename: &str) -> Result<String, io::Error> {
 
 // 4. MULTIPLE ERROR TYPES - Converting errors
 #[derive(Debug)]
+#[allow(dead_code)] // Payloads are only read through Debug
+#[allow(clippy::enum_variant_names)] // The Error suffix keeps the variants self-explanatory
 enum AppError {
     IoError(io::Error),
     ParseError(ParseIntError),
@@ -82,6 +97,7 @@ impl From<ParseIntError> for AppError {
     }
 }
 
+#[allow(dead_code)] // Reference example only; main doesn't read an age file
 fn read_age_from_file(filename: &str) -> Result<u32, AppError> {
     let mut file = File::open(filename)?; // io::Error auto-converted to AppError
     let mut contents = String::new();
@@ -90,9 +106,9 @@ fn read_age_from_file(filename: &str) -> Result<u32, AppError> {
     let age: u32 = contents.trim().parse()?; // ParseIntError auto-converted
 
     if age > 150 {
-        return Err(AppError::ValidationError(
-            String::from("Age seems unrealistic")
-        ));
+        return Err(AppError::ValidationError(String::from(
+            "Age seems unrealistic",
+        )));
     }
 
     Ok(age)
@@ -112,14 +128,78 @@ fn validate_email(email: &str) -> Result<(), String> {
     Ok(())
 }
 
+// Unlike validate_email this reports every problem at once, and returns the digits on success
+fn validate_phone(phone: &str) -> Result<String, Vec<String>> {
+    let cleaned: String = phone
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '(' | ')'))
+        .collect();
+
+    let mut problems = Vec::new();
+    let invalid: Vec<char> = cleaned.chars().filter(|c| !c.is_ascii_digit()).collect();
+    if !invalid.is_empty() {
+        problems.push(format!("Contains non-digit characters: {:?}", invalid));
+    }
+    let digits = cleaned.len() - invalid.iter().map(|c| c.len_utf8()).sum::<usize>();
+    match digits {
+        10 => {}
+        11 if cleaned.starts_with('1') => {}
+        11 => problems.push(String::from("11-digit numbers must start with 1")),
+        n => problems.push(format!("Has {} digits, expected 10 or 11", n)),
+    }
+
+    if problems.is_empty() {
+        Ok(cleaned)
+    } else {
+        Err(problems)
+    }
+}
+
+// Generic bounds check for anything comparable and printable (inclusive range)
+fn validate_range<T: PartialOrd + Copy + Display>(value: T, min: T, max: T) -> Result<T, String> {
+    if value < min {
+        Err(format!("{} is below the minimum of {}", value, min))
+    } else if value > max {
+        Err(format!("{} is above the maximum of {}", value, max))
+    } else {
+        Ok(value)
+    }
+}
+
 // 6. RECOVERABLE OPERATIONS
+// Structured commands instead of raw SQL strings
+#[derive(Debug, Clone, PartialEq)]
+enum Command {
+    Insert(String),
+    Delete(String),
+    Select,
+}
+
 struct Database {
+    host: String,
+    port: u16,
+    max_connections: usize,
+    timeout_secs: u64,
     connected: bool,
+    in_use: bool, // Set while checked out of a DatabasePool
+    rows: Vec<String>,
 }
 
 impl Database {
     fn new() -> Self {
-        Database { connected: false }
+        Database {
+            host: String::from("localhost"),
+            port: 5432,
+            max_connections: 10,
+            timeout_secs: 30,
+            connected: false,
+            in_use: false,
+            rows: Vec::new(),
+        }
+    }
+
+    fn builder() -> DatabaseBuilder {
+        DatabaseBuilder::new()
     }
 
     fn connect(&mut self) -> Result<(), String> {
@@ -141,6 +221,29 @@ impl Database {
         Ok(vec![String::from("result1"), String::from("result2")])
     }
 
+    // Insert/Delete return no rows; Select returns all of them
+    fn execute(&mut self, command: &Command) -> Result<Vec<String>, String> {
+        if !self.connected {
+            return Err(String::from("Not connected to database"));
+        }
+        match command {
+            Command::Insert(row) => {
+                self.rows.push(row.clone());
+                Ok(Vec::new())
+            }
+            Command::Delete(row) => {
+                let index = self
+                    .rows
+                    .iter()
+                    .position(|r| r == row)
+                    .ok_or_else(|| format!("Row '{}' not found", row))?;
+                self.rows.remove(index);
+                Ok(Vec::new())
+            }
+            Command::Select => Ok(self.rows.clone()),
+        }
+    }
+
     fn disconnect(&mut self) -> Result<(), String> {
         if !self.connected {
             return Err(String::from("Already disconnected"));
@@ -148,6 +251,145 @@ impl Database {
         self.connected = false;
         Ok(())
     }
+
+    // Hand a pooled connection back so it can be acquired again
+    fn release(&mut self) {
+        self.in_use = false;
+    }
+}
+
+// Chained configuration for a Database; build() rejects settings that can't work
+struct DatabaseBuilder {
+    host: String,
+    port: u16,
+    max_connections: usize,
+    timeout_secs: u64,
+}
+
+impl DatabaseBuilder {
+    // There is no default host: it must be set before build()
+    fn new() -> Self {
+        DatabaseBuilder {
+            host: String::new(),
+            port: 5432,
+            max_connections: 10,
+            timeout_secs: 30,
+        }
+    }
+
+    fn host(mut self, host: &str) -> Self {
+        self.host = host.to_string();
+        self
+    }
+
+    fn port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    fn max_connections(mut self, max_connections: usize) -> Self {
+        self.max_connections = max_connections;
+        self
+    }
+
+    fn timeout_secs(mut self, timeout_secs: u64) -> Self {
+        self.timeout_secs = timeout_secs;
+        self
+    }
+
+    fn build(self) -> Result<Database, String> {
+        if self.host.trim().is_empty() {
+            return Err(String::from("Host must not be empty"));
+        }
+        if self.port == 0 {
+            return Err(String::from("Port must be nonzero"));
+        }
+        Ok(Database {
+            host: self.host,
+            port: self.port,
+            max_connections: self.max_connections,
+            timeout_secs: self.timeout_secs,
+            ..Database::new()
+        })
+    }
+}
+
+// Wraps a Database and remembers successful mutations so they can be undone
+struct UndoableDatabase {
+    db: Database,
+    history: Vec<Command>,
+}
+
+impl UndoableDatabase {
+    fn new(db: Database) -> Self {
+        UndoableDatabase {
+            db,
+            history: Vec::new(),
+        }
+    }
+
+    fn execute(&mut self, command: Command) -> Result<Vec<String>, String> {
+        let result = self.db.execute(&command)?;
+        if command != Command::Select {
+            self.history.push(command); // Reads don't need undoing
+        }
+        Ok(result)
+    }
+
+    // Applies the inverse of the most recent Insert or Delete.
+    // The entry stays in the history until the inverse succeeds, so a failed undo can be retried.
+    fn undo(&mut self) -> Result<(), String> {
+        let last = self
+            .history
+            .last()
+            .ok_or_else(|| String::from("Nothing to undo"))?;
+        let inverse = match last {
+            Command::Insert(row) => Command::Delete(row.clone()),
+            Command::Delete(row) => Command::Insert(row.clone()),
+            Command::Select => unreachable!("Select is never recorded"),
+        };
+        self.db.execute(&inverse)?;
+        self.history.pop();
+        Ok(())
+    }
+
+    fn row_count(&self) -> usize {
+        self.db.rows.len()
+    }
+}
+
+// A fixed set of connections handed out one at a time
+struct DatabasePool {
+    connections: Vec<Database>,
+}
+
+impl DatabasePool {
+    fn new(size: usize) -> Result<Self, String> {
+        let mut connections = Vec::with_capacity(size);
+        for _ in 0..size {
+            let mut db = Database::new();
+            db.connect()?;
+            connections.push(db);
+        }
+        Ok(DatabasePool { connections })
+    }
+
+    // None when every connection is already in use
+    fn acquire(&mut self) -> Option<&mut Database> {
+        let db = self
+            .connections
+            .iter_mut()
+            .find(|db| db.connected && !db.in_use)?;
+        db.in_use = true;
+        Some(db)
+    }
+
+    fn available_count(&self) -> usize {
+        self.connections
+            .iter()
+            .filter(|db| db.connected && !db.in_use)
+            .count()
+    }
 }
 
 // 7. CHAINING OPERATIONS WITH RESULT
@@ -169,49 +411,269 @@ fn get_username(user_id: u32) -> Option<String> {
     }
 }
 
+// 9. BATCH PROCESSING - Keep every outcome instead of stopping at the first error
+fn process_all<T, U, E, F>(items: Vec<T>, f: F) -> Vec<Result<U, E>>
+where
+    F: Fn(T) -> Result<U, E>,
+{
+    items.into_iter().map(f).collect()
+}
+
+// Expand every item into a Vec and concatenate them, stopping at the first error
+fn flat_map_results<T, U, E, F: Fn(&T) -> Result<Vec<U>, E>>(
+    items: &[T],
+    f: F,
+) -> Result<Vec<U>, E> {
+    let mut output = Vec::new();
+    for item in items {
+        output.extend(f(item)?);
+    }
+    Ok(output)
+}
+
+// Helper for the flat_map_results demo: "123" -> [1, 2, 3]
+fn digits_of(s: &&str) -> Result<Vec<u32>, String> {
+    s.chars()
+        .map(|c| {
+            c.to_digit(10)
+                .ok_or_else(|| format!("'{}' in \"{}\" is not a digit", c, s))
+        })
+        .collect()
+}
+
+// try_fold stops at the first Err, so nothing after the overflowing element is added
+fn checked_sum(nums: &[i64]) -> Result<i64, String> {
+    nums.iter().enumerate().try_fold(0i64, |total, (i, &n)| {
+        total
+            .checked_add(n)
+            .ok_or_else(|| format!("Sum would overflow at index {}", i))
+    })
+}
+
+// Calls op until it succeeds or the attempts run out, recording every outcome.
+// op always runs at least once, since there is no E to return otherwise.
+fn retry_logged<T, E: Display, F: FnMut() -> Result<T, E>>(
+    attempts: u32,
+    mut op: F,
+) -> (Result<T, E>, Vec<String>) {
+    let mut log = Vec::new();
+    let mut attempt = 1;
+    loop {
+        match op() {
+            Ok(value) => {
+                log.push(format!("attempt {}: ok", attempt));
+                return (Ok(value), log);
+            }
+            Err(e) => {
+                log.push(format!("attempt {}: error: {}", attempt, e));
+                if attempt >= attempts {
+                    return (Err(e), log);
+                }
+            }
+        }
+        attempt += 1;
+    }
+}
+
+// 10. GENERIC PARSING - One uniform error message for any FromStr type
+fn safe_parse<T: FromStr>(s: &str) -> Result<T, String> {
+    let input = s.trim();
+    input.parse::<T>().map_err(|_| {
+        format!(
+            "could not parse '{}' as {}",
+            input,
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+// 11. PARSING WITH ERRORS - A small CSV reader
+// Quoted fields may contain commas and newlines; "" inside quotes is a literal quote.
+fn parse_csv(input: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut quoted_field = false; // The current field had quotes, so "" still counts as a field
+    let mut line = 1;
+    let mut quote_line = 1;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push(c);
+                }
+                _ => field.push(c),
+            }
+        } else {
+            match c {
+                '"' => {
+                    in_quotes = true;
+                    quoted_field = true;
+                    quote_line = line;
+                }
+                ',' => {
+                    row.push(std::mem::take(&mut field));
+                    quoted_field = false;
+                }
+                '\n' => {
+                    row.push(std::mem::take(&mut field));
+                    quoted_field = false;
+                    rows.push(std::mem::take(&mut row));
+                    line += 1;
+                }
+                '\r' => {} // Tolerate Windows line endings
+                _ => field.push(c),
+            }
+        }
+    }
+
+    if in_quotes {
+        return Err(format!(
+            "Unterminated quote starting on line {}",
+            quote_line
+        ));
+    }
+    // Last row when the input doesn't end with a newline
+    if !field.is_empty() || quoted_field || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+// 12. CONFIGURABLE OUTPUT - A logger whose verbosity is chosen by the caller
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+enum LogLevel {
+    Quiet,
+    Normal,
+    Verbose,
+}
+
+impl FromStr for LogLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "quiet" => Ok(LogLevel::Quiet),
+            "normal" => Ok(LogLevel::Normal),
+            "verbose" => Ok(LogLevel::Verbose),
+            _ => Err(format!("Unknown log level '{}'", s)),
+        }
+    }
+}
+
+// Writes to any io::Write target: stdout in main, a Vec<u8> buffer in tests
+struct Logger<W: Write> {
+    level: LogLevel,
+    out: W,
+}
+
+impl<W: Write> Logger<W> {
+    fn new(level: LogLevel, out: W) -> Self {
+        Logger { level, out }
+    }
+
+    // Shown at Normal and Verbose
+    fn info(&mut self, args: fmt::Arguments) {
+        if self.level >= LogLevel::Normal {
+            self.write(args);
+        }
+    }
+
+    // Shown only at Verbose
+    fn debug(&mut self, args: fmt::Arguments) {
+        if self.level >= LogLevel::Verbose {
+            self.write(args);
+        }
+    }
+
+    fn write(&mut self, args: fmt::Arguments) {
+        // Logging is best-effort; a failed write shouldn't stop the program
+        let _ = writeln!(self.out, "{}", args);
+    }
+}
+
+// println!-style shorthands: info!(log, "x = {}", x)
+macro_rules! info {
+    ($log:expr, $($arg:tt)*) => {
+        $log.info(format_args!($($arg)*))
+    };
+}
+
+macro_rules! debug {
+    ($log:expr, $($arg:tt)*) => {
+        $log.debug(format_args!($($arg)*))
+    };
+}
+
 fn main() {
-    println!("=== Error Handling in Rust ===\n");
+    // Verbosity comes from the first argument or VERBOSITY, e.g.
+    // `cargo run --example error_handling -- verbose`
+    let level = env::args()
+        .nth(1)
+        .or_else(|| env::var("VERBOSITY").ok())
+        .map(|s| s.parse().unwrap_or(LogLevel::Normal))
+        .unwrap_or(LogLevel::Normal);
+    let mut log = Logger::new(level, io::stdout());
+
+    info!(log, "=== Error Handling in Rust ===\n");
 
     // 1. Basic Result handling
-    println!("--- Basic Result ---");
+    info!(log, "--- Basic Result ---");
     match divide(10.0, 2.0) {
-        Ok(result) => println!("✅ 10 / 2 = {}", result),
-        Err(e) => println!("❌ Error: {}", e),
+        Ok(result) => info!(log, "✅ 10 / 2 = {}", result),
+        Err(e) => info!(log, "❌ Error: {}", e),
     }
 
     match divide(10.0, 0.0) {
-        Ok(result) => println!("✅ 10 / 0 = {}", result),
-        Err(e) => println!("❌ Error: {}", e),
+        Ok(result) => info!(log, "✅ 10 / 0 = {}", result),
+        Err(e) => info!(log, "❌ Error: {}", e),
     }
-    println!();
+    info!(log, "");
 
     // 2. Custom Error Types
-    println!("--- Custom Error Types ---");
+    info!(log, "--- Custom Error Types ---");
     match safe_divide(10.0, 2.0) {
-        Ok(result) => println!("✅ Result: {}", result),
-        Err(MathError::DivisionByZero) => println!("❌ Cannot divide by zero!"),
-        Err(e) => println!("❌ Error: {:?}", e),
+        Ok(result) => info!(log, "✅ Result: {}", result),
+        Err(MathError::DivisionByZero) => info!(log, "❌ Cannot divide by zero!"),
+        Err(e) => info!(log, "❌ Error: {:?}", e),
     }
 
     match square_root(-4.0) {
-        Ok(result) => println!("✅ Square root: {}", result),
+        Ok(result) => info!(log, "✅ Square root: {}", result),
         Err(MathError::NegativeSquareRoot) => {
-            println!("❌ Cannot take square root of negative number!")
+            info!(log, "❌ Cannot take square root of negative number!")
         }
-        Err(e) => println!("❌ Error: {:?}", e),
+        Err(e) => info!(log, "❌ Error: {:?}", e),
     }
-    println!();
+
+    for nums in [&[100.0, 5.0, 2.0][..], &[100.0, 0.0, 2.0], &[]] {
+        match divide_sequence(nums) {
+            Ok(result) => info!(log, "✅ {:?} divided in sequence: {}", nums, result),
+            Err(e) => info!(log, "❌ {:?}: {:?}", nums, e),
+        }
+    }
+    info!(log, "");
 
     // 3. The ? operator demonstration
-    println!("--- File Reading (will fail gracefully) ---");
+    info!(log, "--- File Reading (will fail gracefully) ---");
     match read_username_from_file("nonexistent.txt") {
-        Ok(username) => println!("✅ Username: {}", username),
-        Err(e) => println!("❌ Could not read file: {}", e),
+        Ok(username) => info!(log, "✅ Username: {}", username),
+        Err(e) => info!(log, "❌ Could not read file: {}", e),
     }
-    println!();
+    info!(log, "");
 
     // 4. Validation
-    println!("--- Email Validation ---");
+    info!(log, "--- Email Validation ---");
     let emails = vec![
         "user@example.com",
         "invalid",
@@ -221,102 +683,238 @@ fn main() {
 
     for email in emails {
         match validate_email(email) {
-            Ok(_) => println!("✅ '{}' is valid", email),
-            Err(e) => println!("❌ '{}': {}", email, e),
+            Ok(_) => info!(log, "✅ '{}' is valid", email),
+            Err(e) => info!(log, "❌ '{}': {}", email, e),
         }
     }
-    println!();
+
+    for phone in ["(555) 123-4567", "555-CALL-NOW"] {
+        match validate_phone(phone) {
+            Ok(digits) => info!(log, "✅ '{}' normalizes to {}", phone, digits),
+            Err(problems) => info!(log, "❌ '{}': {}", phone, problems.join("; ")),
+        }
+    }
+
+    // Same validator for different numeric types
+    for age in [30, 130] {
+        match validate_range(age, 0, 120) {
+            Ok(age) => info!(log, "✅ Age {} is valid", age),
+            Err(e) => info!(log, "❌ Age: {}", e),
+        }
+    }
+    for temp in [21.5, -80.0] {
+        match validate_range(temp, -50.0, 60.0) {
+            Ok(temp) => info!(log, "✅ Temperature {}°C is valid", temp),
+            Err(e) => info!(log, "❌ Temperature: {}", e),
+        }
+    }
+    info!(log, "");
 
     // 5. Database operations
-    println!("--- Database Operations ---");
-    let mut db = Database::new();
+    info!(log, "--- Database Operations ---");
+    let mut db = Database::builder()
+        .host("db.example.com")
+        .port(5433)
+        .max_connections(20)
+        .timeout_secs(5)
+        .build()
+        .expect("Valid configuration");
+    debug!(
+        log,
+        "Configured {}:{} (max {} connections, {}s timeout)",
+        db.host,
+        db.port,
+        db.max_connections,
+        db.timeout_secs
+    );
+    if let Err(e) = Database::builder().host("db.example.com").port(0).build() {
+        info!(log, "❌ {}", e);
+    }
 
     // Try query before connecting
     match db.query("SELECT * FROM users") {
-        Ok(results) => println!("✅ Results: {:?}", results),
-        Err(e) => println!("❌ {}", e),
+        Ok(results) => info!(log, "✅ Results: {:?}", results),
+        Err(e) => info!(log, "❌ {}", e),
     }
 
     // Connect and query
     db.connect().expect("Failed to connect");
     match db.query("SELECT * FROM users") {
-        Ok(results) => println!("✅ Results: {:?}", results),
-        Err(e) => println!("❌ {}", e),
+        Ok(results) => info!(log, "✅ Results: {:?}", results),
+        Err(e) => info!(log, "❌ {}", e),
     }
 
     db.disconnect().expect("Failed to disconnect");
-    println!();
+
+    // Connection pool
+    let mut pool = DatabasePool::new(2).expect("Failed to create pool");
+    if let Some(conn) = pool.acquire() {
+        match conn.query("SELECT * FROM orders") {
+            Ok(results) => info!(log, "✅ Pooled results: {:?}", results),
+            Err(e) => info!(log, "❌ {}", e),
+        }
+        conn.release();
+    }
+    debug!(log, "Available connections: {}", pool.available_count());
+
+    // Structured commands with undo
+    let mut connected_db = Database::new();
+    connected_db.connect().expect("Failed to connect");
+    let mut undoable = UndoableDatabase::new(connected_db);
+    for command in [
+        Command::Insert(String::from("alice")),
+        Command::Insert(String::from("bob")),
+        Command::Delete(String::from("carol")),
+    ] {
+        if let Err(e) = undoable.execute(command) {
+            info!(log, "❌ {}", e);
+        }
+    }
+    info!(log, "Rows: {:?}", undoable.execute(Command::Select));
+    undoable.undo().expect("Undo should succeed");
+    info!(log, "After undo: {:?}", undoable.execute(Command::Select));
+    debug!(log, "Row count: {}", undoable.row_count());
+    info!(log, "");
 
     // 6. Chaining operations
-    println!("--- Chaining Operations ---");
+    info!(log, "--- Chaining Operations ---");
     let inputs = vec!["10", "abc", "999999999999999"];
 
     for input in inputs {
         match process_number(input) {
-            Ok(result) => println!("✅ {} * 2 = {}", input, result),
-            Err(e) => println!("❌ '{}': {}", input, e),
+            Ok(result) => info!(log, "✅ {} * 2 = {}", input, result),
+            Err(e) => info!(log, "❌ '{}': {}", input, e),
         }
     }
-    println!();
+    info!(log, "");
 
     // 7. unwrap_or and unwrap_or_else
-    println!("--- Default Values ---");
+    info!(log, "--- Default Values ---");
     let user1 = get_username(1).unwrap_or(String::from("Guest"));
     let user2 = get_username(99).unwrap_or(String::from("Guest"));
 
-    println!("User 1: {}", user1);
-    println!("User 99: {}", user2);
+    info!(log, "User 1: {}", user1);
+    info!(log, "User 99: {}", user2);
 
     let user3 = get_username(99).unwrap_or_else(|| {
-        println!("Computing default username...");
+        debug!(log, "Computing default username...");
         String::from("Guest")
     });
-    println!("User 99 (with computation): {}", user3);
-    println!();
+    info!(log, "User 99 (with computation): {}", user3);
+    info!(log, "");
 
     // 8. Result methods
-    println!("--- Result Methods ---");
+    info!(log, "--- Result Methods ---");
     let result: Result<i32, &str> = Ok(42);
-    println!("Is Ok? {}", result.is_ok());
-    println!("Is Err? {}", result.is_err());
-    println!("Value or default: {}", result.unwrap_or(0));
+    debug!(log, "Is Ok? {}", result.is_ok());
+    debug!(log, "Is Err? {}", result.is_err());
+    #[allow(clippy::unnecessary_literal_unwrap)] // The value is known here on purpose
+    let value = result.unwrap_or(0);
+    info!(log, "Value or default: {}", value);
 
     let error_result: Result<i32, &str> = Err("Something went wrong");
-    println!("\nError result is Ok? {}", error_result.is_ok());
-    println!("Error or default: {}", error_result.unwrap_or(-1));
-    println!();
+    debug!(log, "\nError result is Ok? {}", error_result.is_ok());
+    #[allow(clippy::unnecessary_literal_unwrap)]
+    let fallback = error_result.unwrap_or(-1);
+    info!(log, "Error or default: {}", fallback);
+    info!(log, "");
 
     // 9. Multiple error handling strategies
-    println!("--- Error Handling Strategies ---");
+    info!(log, "--- Error Handling Strategies ---");
 
     // Strategy 1: Match
     match divide(10.0, 0.0) {
-        Ok(v) => println!("Result: {}", v),
-        Err(e) => println!("Handled error: {}", e),
+        Ok(v) => info!(log, "Result: {}", v),
+        Err(e) => info!(log, "Handled error: {}", e),
     }
 
     // Strategy 2: if let for simpler cases
     if let Err(e) = divide(5.0, 0.0) {
-        println!("Error occurred: {}", e);
+        info!(log, "Error occurred: {}", e);
     }
 
     // Strategy 3: unwrap_or for defaults
     let result = divide(10.0, 2.0).unwrap_or(0.0);
-    println!("Result with default: {}", result);
+    info!(log, "Result with default: {}", result);
 
     // Strategy 4: map for transforming success values
     let doubled = divide(10.0, 2.0)
         .map(|x| x * 2.0)
         .unwrap_or(0.0);
-    println!("Doubled result: {}", doubled);
+    info!(log, "Doubled result: {}", doubled);
 
     // Strategy 5: and_then for chaining operations
     let chained = divide(10.0, 2.0)
         .and_then(|x| divide(x, 2.0))
         .unwrap_or(0.0);
-    println!("Chained operations: {}", chained);
+    info!(log, "Chained operations: {}", chained);
+    info!(log, "");
+
+    // 10. Batch processing without short-circuiting
+    info!(log, "--- Batch Processing ---");
+    let pairs = vec![(10.0, 2.0), (5.0, 0.0), (9.0, 3.0)];
+    let outcomes = process_all(pairs, |(a, b)| divide(a, b));
+
+    for (i, outcome) in outcomes.iter().enumerate() {
+        match outcome {
+            Ok(v) => info!(log, "✅ Item {}: {}", i, v),
+            Err(e) => info!(log, "❌ Item {}: {}", i, e),
+        }
+    }
+
+    // ...versus flat-mapping, which stops at the first error
+    info!(
+        log,
+        "Digits: {:?}",
+        flat_map_results(&["12", "345"], digits_of)
+    );
+    info!(
+        log,
+        "Digits: {:?}",
+        flat_map_results(&["12", "3x5", "6"], digits_of)
+    );
+    info!(log, "Checked sum: {:?}", checked_sum(&[1, 2, 3]));
+    info!(log, "Checked sum: {:?}", checked_sum(&[1, i64::MAX, 3]));
+
+    // Retrying a flaky operation, keeping a record of each attempt
+    let mut calls = 0;
+    let (outcome, attempts) = retry_logged(4, || {
+        calls += 1;
+        if calls < 3 {
+            Err(format!("timeout #{}", calls))
+        } else {
+            Ok(calls)
+        }
+    });
+    for line in &attempts {
+        debug!(log, "  {}", line);
+    }
+    info!(log, "Retry outcome: {:?}", outcome);
+    info!(log, "");
+
+    // 11. Generic parsing with uniform errors
+    info!(log, "--- Generic Parsing ---");
+    info!(log, "{:?}", safe_parse::<i32>(" 42 "));
+    info!(log, "{:?}", safe_parse::<f64>("3.5"));
+    info!(log, "{:?}", safe_parse::<bool>("maybe"));
+    info!(log, "");
+
+    // 12. CSV parsing
+    info!(log, "--- CSV Parsing ---");
+    let csv = "name,quote\nAda,\"Hello, world\"\nBob,\"She said \"\"hi\"\"\"\n";
+    match parse_csv(csv) {
+        Ok(rows) => {
+            for row in rows {
+                info!(log, "✅ {:?}", row);
+            }
+        }
+        Err(e) => info!(log, "❌ {}", e),
+    }
+    if let Err(e) = parse_csv("a,\"oops\nb,c") {
+        info!(log, "❌ {}", e);
+    }
 
-    println!("\n🎉 You've mastered error handling in Rust!");
+    info!(log, "\n🎉 You've mastered error handling in Rust!");
 }
 
 #[cfg(test)]
@@ -345,6 +943,23 @@ mod tests {
         assert_eq!(square_root(-4.0), Err(MathError::NegativeSquareRoot));
     }
 
+    #[test]
+    fn test_divide_sequence() {
+        assert_eq!(divide_sequence(&[100.0, 5.0, 2.0]), Ok(10.0));
+        assert_eq!(divide_sequence(&[1.0, 4.0]), Ok(0.25));
+        assert_eq!(divide_sequence(&[0.0, 4.0]), Ok(0.0));
+        assert_eq!(divide_sequence(&[7.5]), Ok(7.5));
+    }
+
+    #[test]
+    fn test_divide_sequence_errors() {
+        assert_eq!(
+            divide_sequence(&[10.0, 2.0, 0.0, 5.0]),
+            Err(MathError::DivisionByZero)
+        );
+        assert_eq!(divide_sequence(&[]), Err(MathError::EmptyInput));
+    }
+
     #[test]
     fn test_validate_email() {
         assert!(validate_email("user@example.com").is_ok());
@@ -364,9 +979,351 @@ mod tests {
         assert!(db.query("SELECT *").is_err()); // Disconnected
     }
 
+    #[test]
+    fn test_database_builder() {
+        let db = Database::builder()
+            .host("db.example.com")
+            .port(6543)
+            .max_connections(4)
+            .timeout_secs(15)
+            .build()
+            .unwrap();
+        assert_eq!(db.host, "db.example.com");
+        assert_eq!(db.port, 6543);
+        assert_eq!(db.max_connections, 4);
+        assert_eq!(db.timeout_secs, 15);
+        assert!(!db.connected);
+    }
+
+    #[test]
+    fn test_database_builder_missing_host() {
+        let result = Database::builder().port(6543).build();
+        assert_eq!(result.err(), Some(String::from("Host must not be empty")));
+    }
+
+    #[test]
+    fn test_database_builder_zero_port() {
+        let result = Database::builder().host("localhost").port(0).build();
+        assert_eq!(result.err(), Some(String::from("Port must be nonzero")));
+    }
+
     #[test]
     fn test_process_number() {
         assert_eq!(process_number("5"), Ok(10));
         assert!(process_number("abc").is_err());
     }
+
+    #[test]
+    fn test_process_all() {
+        let pairs = vec![(10.0, 2.0), (5.0, 0.0), (9.0, 3.0), (1.0, 0.0)];
+        let outcomes = process_all(pairs, |(a, b)| divide(a, b));
+
+        // One outcome per input, errors don't stop processing
+        assert_eq!(outcomes.len(), 4);
+        assert_eq!(outcomes[0], Ok(5.0));
+        assert!(outcomes[1].is_err());
+        assert_eq!(outcomes[2], Ok(3.0));
+        assert!(outcomes[3].is_err());
+    }
+
+    #[test]
+    fn test_safe_parse() {
+        assert_eq!(safe_parse::<i32>(" 42 "), Ok(42));
+        assert_eq!(
+            safe_parse::<i32>("4x2"),
+            Err(String::from("could not parse '4x2' as i32"))
+        );
+
+        assert_eq!(safe_parse::<f64>("2.5"), Ok(2.5));
+        assert_eq!(
+            safe_parse::<f64>("abc"),
+            Err(String::from("could not parse 'abc' as f64"))
+        );
+
+        assert_eq!(safe_parse::<bool>("true"), Ok(true));
+        assert_eq!(
+            safe_parse::<bool>("yes"),
+            Err(String::from("could not parse 'yes' as bool"))
+        );
+    }
+
+    #[test]
+    fn test_database_pool() {
+        let mut pool = DatabasePool::new(2).unwrap();
+        assert_eq!(pool.available_count(), 2);
+
+        // Acquire up to capacity
+        assert!(pool.acquire().is_some());
+        assert!(pool.acquire().unwrap().query("SELECT *").is_ok());
+        assert_eq!(pool.available_count(), 0);
+
+        // Exhausted
+        assert!(pool.acquire().is_none());
+
+        // Releasing makes the connection available again
+        pool.connections[0].release();
+        assert_eq!(pool.available_count(), 1);
+        assert!(pool.acquire().is_some());
+        assert!(pool.acquire().is_none());
+    }
+
+    #[test]
+    fn test_validate_phone() {
+        assert_eq!(
+            validate_phone("(555) 123-4567"),
+            Ok(String::from("5551234567"))
+        );
+        assert_eq!(
+            validate_phone("1-800-555-0199"),
+            Ok(String::from("18005550199"))
+        );
+    }
+
+    #[test]
+    fn test_validate_phone_problems() {
+        assert_eq!(
+            validate_phone("555-12ab-567"),
+            Err(vec![
+                String::from("Contains non-digit characters: ['a', 'b']"),
+                String::from("Has 8 digits, expected 10 or 11"),
+            ])
+        );
+        assert_eq!(
+            validate_phone("555-1234"),
+            Err(vec![String::from("Has 7 digits, expected 10 or 11")])
+        );
+        assert_eq!(
+            validate_phone("25551234567"),
+            Err(vec![String::from("11-digit numbers must start with 1")])
+        );
+    }
+
+    #[test]
+    fn test_validate_range() {
+        assert_eq!(validate_range(5, 1, 10), Ok(5));
+        assert_eq!(
+            validate_range(0, 1, 10),
+            Err(String::from("0 is below the minimum of 1"))
+        );
+        assert_eq!(
+            validate_range(42, 1, 10),
+            Err(String::from("42 is above the maximum of 10"))
+        );
+
+        // Bounds are inclusive
+        assert_eq!(validate_range(1, 1, 10), Ok(1));
+        assert_eq!(validate_range(10, 1, 10), Ok(10));
+        assert_eq!(validate_range(-50.0, -50.0, 60.0), Ok(-50.0));
+    }
+
+    #[test]
+    fn test_parse_csv_simple() {
+        assert_eq!(
+            parse_csv("a,b,c\n1,2,3\n"),
+            Ok(vec![
+                vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                vec!["1".to_string(), "2".to_string(), "3".to_string()],
+            ])
+        );
+        assert_eq!(parse_csv(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_parse_csv_quoted_fields() {
+        // Commas inside quotes stay in the field
+        assert_eq!(
+            parse_csv("x,\"a, b\",y"),
+            Ok(vec![vec![
+                "x".to_string(),
+                "a, b".to_string(),
+                "y".to_string()
+            ]])
+        );
+
+        // "" is an escaped quote
+        assert_eq!(
+            parse_csv("\"say \"\"hi\"\"\""),
+            Ok(vec![vec!["say \"hi\"".to_string()]])
+        );
+
+        // A quoted empty field is still a field, even as the whole last row
+        assert_eq!(parse_csv("\"\""), Ok(vec![vec![String::new()]]));
+        assert_eq!(
+            parse_csv("a\n\"\""),
+            Ok(vec![vec!["a".to_string()], vec![String::new()]])
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_unterminated_quote() {
+        assert_eq!(
+            parse_csv("a,b\nc,\"unfinished"),
+            Err("Unterminated quote starting on line 2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_undo_while_disconnected_keeps_history() {
+        let mut undoable = connected_undoable();
+        undoable
+            .execute(Command::Insert(String::from("alice")))
+            .unwrap();
+
+        undoable.db.disconnect().unwrap();
+        assert_eq!(
+            undoable.undo(),
+            Err(String::from("Not connected to database"))
+        );
+        assert_eq!(undoable.history.len(), 1);
+
+        // Once reconnected the same undo goes through
+        undoable.db.connect().unwrap();
+        assert!(undoable.undo().is_ok());
+        assert_eq!(undoable.row_count(), 0);
+        assert!(undoable.undo().is_err());
+    }
+
+    fn connected_undoable() -> UndoableDatabase {
+        let mut db = Database::new();
+        db.connect().unwrap();
+        UndoableDatabase::new(db)
+    }
+
+    #[test]
+    fn test_undo_insert_and_delete() {
+        let mut db = connected_undoable();
+        db.execute(Command::Insert(String::from("a"))).unwrap();
+        db.execute(Command::Insert(String::from("b"))).unwrap();
+        db.execute(Command::Select).unwrap();
+        assert_eq!(db.row_count(), 2);
+
+        // Select isn't recorded, so this undoes the second insert
+        db.undo().unwrap();
+        assert_eq!(db.row_count(), 1);
+        assert_eq!(db.execute(Command::Select), Ok(vec![String::from("a")]));
+
+        db.execute(Command::Delete(String::from("a"))).unwrap();
+        assert_eq!(db.row_count(), 0);
+        db.undo().unwrap();
+        assert_eq!(db.row_count(), 1);
+    }
+
+    #[test]
+    fn test_undo_empty_history() {
+        let mut db = connected_undoable();
+        assert_eq!(db.undo(), Err(String::from("Nothing to undo")));
+
+        // Failed commands aren't recorded either
+        assert!(db.execute(Command::Delete(String::from("ghost"))).is_err());
+        assert_eq!(db.undo(), Err(String::from("Nothing to undo")));
+    }
+
+    #[test]
+    fn test_flat_map_results() {
+        assert_eq!(
+            flat_map_results(&["12", "", "345"], digits_of),
+            Ok(vec![1, 2, 3, 4, 5])
+        );
+
+        // Stops at the middle item; "6" is never reached
+        assert_eq!(
+            flat_map_results(&["12", "3x5", "6"], digits_of),
+            Err(String::from("'x' in \"3x5\" is not a digit"))
+        );
+    }
+
+    #[test]
+    fn test_checked_sum() {
+        assert_eq!(checked_sum(&[1, 2, 3, -4]), Ok(2));
+        assert_eq!(checked_sum(&[]), Ok(0));
+    }
+
+    #[test]
+    fn test_checked_sum_overflow_index() {
+        assert_eq!(
+            checked_sum(&[5, i64::MAX - 10, 3, 4, 1]),
+            Err(String::from("Sum would overflow at index 3"))
+        );
+        assert_eq!(
+            checked_sum(&[-1, i64::MIN]),
+            Err(String::from("Sum would overflow at index 1"))
+        );
+    }
+
+    #[test]
+    fn test_retry_logged_first_attempt() {
+        let (result, log) = retry_logged(3, || Ok::<_, String>(42));
+        assert_eq!(result, Ok(42));
+        assert_eq!(log, vec!["attempt 1: ok"]);
+    }
+
+    #[test]
+    fn test_retry_logged_after_failures() {
+        let mut remaining_failures = 2;
+        let (result, log) = retry_logged(5, || {
+            if remaining_failures > 0 {
+                remaining_failures -= 1;
+                Err("busy")
+            } else {
+                Ok("done")
+            }
+        });
+        assert_eq!(result, Ok("done"));
+        assert_eq!(
+            log,
+            vec![
+                "attempt 1: error: busy",
+                "attempt 2: error: busy",
+                "attempt 3: ok"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_retry_logged_total_failure() {
+        let mut calls = 0;
+        let (result, log) = retry_logged(3, || {
+            calls += 1;
+            "x".parse::<i32>().map(|_| calls)
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 3);
+        assert_eq!(log.len(), 3);
+        assert_eq!(log[2], "attempt 3: error: invalid digit found in string");
+
+        // Zero attempts still runs once
+        let (_, log) = retry_logged(0, || Err::<(), _>("nope"));
+        assert_eq!(log, vec!["attempt 1: error: nope"]);
+    }
+
+    #[test]
+    fn test_log_level_parsing() {
+        assert_eq!("quiet".parse(), Ok(LogLevel::Quiet));
+        assert_eq!("Verbose".parse(), Ok(LogLevel::Verbose));
+        assert!("loud".parse::<LogLevel>().is_err());
+    }
+
+    #[test]
+    fn test_quiet_logger_suppresses_output() {
+        let mut log = Logger::new(LogLevel::Quiet, Vec::new());
+        info!(log, "hello {}", 1);
+        debug!(log, "details {}", 2);
+        assert!(log.out.is_empty());
+    }
+
+    #[test]
+    fn test_logger_levels() {
+        let mut normal = Logger::new(LogLevel::Normal, Vec::new());
+        info!(normal, "shown");
+        debug!(normal, "hidden");
+        assert_eq!(String::from_utf8(normal.out).unwrap(), "shown\n");
+
+        let mut verbose = Logger::new(LogLevel::Verbose, Vec::new());
+        info!(verbose, "shown");
+        debug!(verbose, "also shown");
+        assert_eq!(
+            String::from_utf8(verbose.out).unwrap(),
+            "shown\nalso shown\n"
+        );
+    }
 }
@@ -1,7 +1,9 @@
 // Structs and Methods Example
 // This demonstrates how Rust replaces classes with structs, impl blocks, and traits
 
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::Hash;
 
 // 1. BASIC STRUCT - Like a class without methods
 #[derive(Debug)] // Automatically implement Debug trait for printing
@@ -50,11 +52,61 @@ impl Dog {
     }
 }
 
+// Display is for user-facing output, Debug is for developers
+impl fmt::Display for Dog {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} the {}-year-old {}", self.name, self.age, self.breed)?;
+        if self.is_good_dog {
+            write!(f, " (a good dog)")?;
+        }
+        Ok(())
+    }
+}
+
 // 3. ANOTHER STRUCT EXAMPLE - Bank Account
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TransactionKind {
+    Deposit,
+    Withdrawal,
+    Fee,
+}
+
+impl TransactionKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TransactionKind::Deposit => "deposit",
+            TransactionKind::Withdrawal => "withdrawal",
+            TransactionKind::Fee => "fee",
+        }
+    }
+}
+
+// One entry in an account's history
+#[derive(Debug, Clone, PartialEq)]
+struct Transaction {
+    kind: TransactionKind,
+    amount: f64,
+}
+
 struct BankAccount {
     account_number: String,
     owner: String,
     balance: f64,
+    minimum_balance: f64,
+    transactions: Vec<Transaction>,
+    category: String, // Budget category, e.g. "savings" or "groceries"
+    withdrawal_fee: f64,
+    minimum_transaction: f64,
+    rate_limit: Option<RateLimit>,
+    clock: u64,                   // Logical time; callers advance it explicitly
+    recent_withdrawals: Vec<u64>, // Clock readings of withdrawals still inside the window
+}
+
+// At most max_withdrawals within any window of `window` clock ticks
+#[derive(Debug, Clone, Copy)]
+struct RateLimit {
+    max_withdrawals: usize,
+    window: u64,
 }
 
 impl BankAccount {
@@ -63,24 +115,121 @@ impl BankAccount {
             account_number,
             owner,
             balance: 0.0,
+            minimum_balance: 0.0,
+            transactions: Vec::new(),
+            category: String::from("general"),
+            withdrawal_fee: 0.0,
+            minimum_transaction: 0.0,
+            rate_limit: None,
+            clock: 0,
+            recent_withdrawals: Vec::new(),
         }
     }
 
-    fn deposit(&mut self, amount: f64) {
-        if amount > 0.0 {
-            self.balance += amount;
-            println!("💰 Deposited ${:.2}. New balance: ${:.2}", amount, self.balance);
+    // Alternative constructor for accounts tagged with a budget category
+    fn with_category(account_number: String, owner: String, category: String) -> Self {
+        BankAccount {
+            category,
+            ..BankAccount::new(account_number, owner)
+        }
+    }
+
+    // Alternative constructor for accounts that must keep a minimum balance
+    fn with_minimum_balance(account_number: String, owner: String, minimum_balance: f64) -> Self {
+        BankAccount {
+            minimum_balance,
+            ..BankAccount::new(account_number, owner)
         }
     }
 
+    // Alternative constructor for accounts with a flat withdrawal fee and a minimum amount
+    fn with_fees(
+        account_number: String,
+        owner: String,
+        withdrawal_fee: f64,
+        minimum_transaction: f64,
+    ) -> Self {
+        BankAccount {
+            withdrawal_fee,
+            minimum_transaction,
+            ..BankAccount::new(account_number, owner)
+        }
+    }
+
+    // Alternative constructor for accounts limited to max_withdrawals per window of clock ticks
+    fn with_rate_limit(
+        account_number: String,
+        owner: String,
+        max_withdrawals: usize,
+        window: u64,
+    ) -> Self {
+        BankAccount {
+            rate_limit: Some(RateLimit {
+                max_withdrawals,
+                window,
+            }),
+            ..BankAccount::new(account_number, owner)
+        }
+    }
+
+    // The clock never reads the system time, which keeps rate limiting deterministic
+    fn advance_clock(&mut self, ticks: u64) {
+        self.clock += ticks;
+    }
+
+    fn deposit(&mut self, amount: f64) -> Result<(), String> {
+        if amount <= 0.0 {
+            return Err("Amount must be positive".to_string());
+        }
+        if amount < self.minimum_transaction {
+            return Err(format!(
+                "Amount is below the minimum transaction of ${:.2}",
+                self.minimum_transaction
+            ));
+        }
+        self.balance += amount;
+        self.record(TransactionKind::Deposit, amount);
+        println!(
+            "💰 Deposited ${:.2}. New balance: ${:.2}",
+            amount, self.balance
+        );
+        Ok(())
+    }
+
     fn withdraw(&mut self, amount: f64) -> Result<(), String> {
         if amount <= 0.0 {
             return Err("Amount must be positive".to_string());
         }
-        if amount > self.balance {
+        if let Some(limit) = self.rate_limit {
+            let now = self.clock;
+            self.recent_withdrawals
+                .retain(|&at| now - at < limit.window);
+            if self.recent_withdrawals.len() >= limit.max_withdrawals {
+                return Err("Rate limit exceeded".to_string());
+            }
+        }
+        if amount < self.minimum_transaction {
+            return Err(format!(
+                "Amount is below the minimum transaction of ${:.2}",
+                self.minimum_transaction
+            ));
+        }
+        // The fee comes out of the balance too
+        let total = amount + self.withdrawal_fee;
+        if total > self.balance {
             return Err("Insufficient funds".to_string());
         }
-        self.balance -= amount;
+        if self.balance - total < self.minimum_balance {
+            return Err("Withdrawal would drop below minimum balance".to_string());
+        }
+        self.balance -= total;
+        if self.rate_limit.is_some() {
+            self.recent_withdrawals.push(self.clock);
+        }
+        self.record(TransactionKind::Withdrawal, amount);
+        if self.withdrawal_fee > 0.0 {
+            self.record(TransactionKind::Fee, self.withdrawal_fee);
+        }
         println!("💸 Withdrew ${:.2}. New balance: ${:.2}", amount, self.balance);
         Ok(())
     }
@@ -88,6 +237,365 @@ impl BankAccount {
     fn get_balance(&self) -> f64 {
         self.balance
     }
+
+    // Month-by-month compound growth at annual_rate (e.g. 0.05), without touching the account
+    fn project_balance(&self, annual_rate: f64, months: u32) -> Vec<(u32, f64)> {
+        let monthly_rate = annual_rate / 12.0;
+        let mut balance = self.balance;
+        (1..=months)
+            .map(|month| {
+                balance *= 1.0 + monthly_rate;
+                // Round only what we report so rounding errors don't compound
+                (month, (balance * 100.0).round() / 100.0)
+            })
+            .collect()
+    }
+
+    fn record(&mut self, kind: TransactionKind, amount: f64) {
+        self.transactions.push(Transaction { kind, amount });
+    }
+
+    // Statement as CSV, one row per transaction (seq starts at 1)
+    fn export_csv(&self) -> String {
+        let mut csv = String::from("seq,type,amount\n");
+        for (i, tx) in self.transactions.iter().enumerate() {
+            csv.push_str(&format!(
+                "{},{},{:.2}\n",
+                i + 1,
+                tx.kind.as_str(),
+                tx.amount
+            ));
+        }
+        csv
+    }
+}
+
+// EVENT SOURCING - Rebuild an account's state by replaying its history from zero
+fn replay_events(owner: String, events: &[(&str, f64)]) -> Result<BankAccount, String> {
+    let mut account = BankAccount::new(String::from("replayed"), owner);
+    for (i, &(kind, amount)) in events.iter().enumerate() {
+        match kind {
+            "deposit" => account
+                .deposit(amount)
+                .map_err(|e| format!("Event {}: deposit of ${:.2} failed: {}", i, amount, e))?,
+            "withdraw" => account
+                .withdraw(amount)
+                .map_err(|e| format!("Event {}: withdraw of ${:.2} failed: {}", i, amount, e))?,
+            other => return Err(format!("Event {}: unknown event type '{}'", i, other)),
+        }
+    }
+    Ok(account)
+}
+
+// COMPOSITION - Rust has no inheritance, so a SavingsAccount wraps a BankAccount
+struct SavingsAccount {
+    account: BankAccount,
+    interest_rate: f64, // Annual rate, e.g. 0.06 for 6%
+}
+
+impl SavingsAccount {
+    fn new(account: BankAccount, interest_rate: f64) -> Self {
+        SavingsAccount {
+            account,
+            interest_rate,
+        }
+    }
+
+    // Apply one month of interest to the wrapped account (an empty account earns nothing)
+    fn accrue_monthly(&mut self) -> Result<(), String> {
+        let interest = self.account.get_balance() * self.interest_rate / 12.0;
+        if interest > 0.0 {
+            self.account.deposit(interest)?;
+        }
+        Ok(())
+    }
+
+    // Delegate the rest to the inner account
+    fn deposit(&mut self, amount: f64) -> Result<(), String> {
+        self.account.deposit(amount)
+    }
+
+    fn withdraw(&mut self, amount: f64) -> Result<(), String> {
+        self.account.withdraw(amount)
+    }
+
+    fn get_balance(&self) -> f64 {
+        self.account.get_balance()
+    }
+}
+
+// What batch_transfer needs to undo its changes to one account
+struct AccountSnapshot {
+    balance: f64,
+    transaction_count: usize, // history is append-only, so truncating undoes it
+    recent_withdrawals: Vec<u64>, // may be pruned by withdraw(), so kept whole
+}
+
+impl BankAccount {
+    fn snapshot(&self) -> AccountSnapshot {
+        AccountSnapshot {
+            balance: self.balance,
+            transaction_count: self.transactions.len(),
+            recent_withdrawals: self.recent_withdrawals.clone(),
+        }
+    }
+
+    fn restore(&mut self, snapshot: AccountSnapshot) {
+        self.balance = snapshot.balance;
+        self.transactions.truncate(snapshot.transaction_count);
+        self.recent_withdrawals = snapshot.recent_withdrawals;
+    }
+}
+
+// MULTIPLE ACCOUNTS - A Bank owns many BankAccounts keyed by account number
+struct Bank {
+    accounts: HashMap<String, BankAccount>,
+}
+
+impl Bank {
+    fn new() -> Self {
+        Bank {
+            accounts: HashMap::new(),
+        }
+    }
+
+    fn open_account(&mut self, account: BankAccount) -> Result<(), String> {
+        if self.accounts.contains_key(&account.account_number) {
+            return Err(format!("Account {} already exists", account.account_number));
+        }
+        self.accounts
+            .insert(account.account_number.clone(), account);
+        Ok(())
+    }
+
+    fn account_mut(&mut self, account_number: &str) -> Option<&mut BankAccount> {
+        self.accounts.get_mut(account_number)
+    }
+
+    // Total balance held in each budget category
+    fn spending_by_category(&self) -> HashMap<String, f64> {
+        let mut totals = HashMap::new();
+        for account in self.accounts.values() {
+            *totals.entry(account.category.clone()).or_insert(0.0) += account.balance;
+        }
+        totals
+    }
+
+    // Pays every (destination, amount) out of `from`, all or nothing: every payout is
+    // attempted so all problems are reported, then any failure rolls the whole batch back.
+    fn batch_transfer(&mut self, from: &str, payouts: &[(String, f64)]) -> Result<(), Vec<String>> {
+        if !self.accounts.contains_key(from) {
+            return Err(vec![format!("Unknown source account {}", from)]);
+        }
+        let snapshots: Vec<(String, AccountSnapshot)> = self
+            .accounts
+            .iter()
+            .filter(|(number, _)| {
+                number.as_str() == from || payouts.iter().any(|(to, _)| to == *number)
+            })
+            .map(|(number, account)| (number.clone(), account.snapshot()))
+            .collect();
+
+        let mut errors = Vec::new();
+        for (i, (to, amount)) in payouts.iter().enumerate() {
+            if let Err(e) = self.transfer(from, to, *amount) {
+                errors.push(format!("Payout {} to {}: {}", i, to, e));
+            }
+        }
+        if errors.is_empty() {
+            return Ok(());
+        }
+
+        for (number, snapshot) in snapshots {
+            if let Some(account) = self.accounts.get_mut(&number) {
+                account.restore(snapshot);
+            }
+        }
+        Err(errors)
+    }
+
+    // One payout; if the deposit is refused the withdrawal is undone, so no money is lost
+    fn transfer(&mut self, from: &str, to: &str, amount: f64) -> Result<(), String> {
+        if from == to {
+            return Err(String::from("Cannot transfer to the source account"));
+        }
+        if !self.accounts.contains_key(to) {
+            return Err(String::from("Unknown account"));
+        }
+        let source = self
+            .accounts
+            .get_mut(from)
+            .ok_or_else(|| String::from("Unknown source account"))?;
+        let before = source.snapshot();
+        source.withdraw(amount)?;
+
+        let deposited = match self.accounts.get_mut(to) {
+            Some(destination) => destination.deposit(amount),
+            None => Err(String::from("Unknown account")),
+        };
+        if let Err(e) = deposited {
+            if let Some(source) = self.accounts.get_mut(from) {
+                source.restore(before);
+            }
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    // Compounds every account monthly for `months` and returns the total interest paid.
+    // Each account's interest is rounded to cents and recorded as a deposit.
+    fn apply_interest_all(&mut self, annual_rate: f64, months: u32) -> Result<f64, String> {
+        if annual_rate < 0.0 || annual_rate.is_nan() {
+            return Err(String::from("Interest rate cannot be negative"));
+        }
+        if months == 0 {
+            return Err(String::from(
+                "Interest must be applied for at least one month",
+            ));
+        }
+
+        let growth = (1.0 + annual_rate / 12.0).powi(months as i32);
+        let mut total = 0.0;
+        for account in self.accounts.values_mut() {
+            let interest = (account.balance * (growth - 1.0) * 100.0).round() / 100.0;
+            if interest > 0.0 {
+                account.balance += interest;
+                account.record(TransactionKind::Deposit, interest);
+                total += interest;
+            }
+        }
+        Ok(total)
+    }
+}
+
+// LOAN AMORTIZATION - Fixed monthly payment split into interest and principal
+// (month, payment, interest, principal, remaining balance)
+type LoanRow = (u32, f64, f64, f64, f64);
+
+fn loan_schedule(principal: f64, annual_rate: f64, months: u32) -> Result<Vec<LoanRow>, String> {
+    // NaN fails every comparison, so it has to be rejected explicitly
+    if principal <= 0.0 || !principal.is_finite() {
+        return Err(String::from("Principal must be a positive, finite amount"));
+    }
+    if annual_rate < 0.0 || !annual_rate.is_finite() {
+        return Err(String::from(
+            "Interest rate must be finite and not negative",
+        ));
+    }
+    if months == 0 {
+        return Err(String::from("Loan term must be at least one month"));
+    }
+
+    let monthly_rate = annual_rate / 12.0;
+    let payment = if monthly_rate == 0.0 {
+        principal / months as f64
+    } else {
+        // Standard annuity formula: P * r / (1 - (1 + r)^-n)
+        principal * monthly_rate / (1.0 - (1.0 + monthly_rate).powi(-(months as i32)))
+    };
+
+    let mut balance = principal;
+    let schedule = (1..=months)
+        .map(|month| {
+            let interest = balance * monthly_rate;
+            let principal_part = payment - interest;
+            balance -= principal_part;
+            (month, payment, interest, principal_part, balance)
+        })
+        .collect();
+    Ok(schedule)
+}
+
+// NEWTYPE - Money stored as whole cents avoids float rounding errors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Money(i64);
+
+impl Money {
+    fn from_dollars(dollars: f64) -> Self {
+        Money((dollars * 100.0).round() as i64)
+    }
+
+    fn to_dollars(self) -> f64 {
+        self.0 as f64 / 100.0
+    }
+
+    // None on overflow instead of panicking
+    fn checked_add(self, other: Money) -> Option<Money> {
+        self.0.checked_add(other.0).map(Money)
+    }
+
+    fn checked_sub(self, other: Money) -> Option<Money> {
+        self.0.checked_sub(other.0).map(Money)
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let cents = self.0.unsigned_abs();
+        write!(f, "{}${}.{:02}", sign, cents / 100, cents % 100)
+    }
+}
+
+// STRUCTS CONTAINING STRUCTS - A shopping cart made of line items
+struct CartItem {
+    name: String,
+    price: f64,
+    quantity: u32,
+}
+
+impl CartItem {
+    fn subtotal(&self) -> f64 {
+        self.price * self.quantity as f64
+    }
+}
+
+struct Cart {
+    items: Vec<CartItem>,
+}
+
+impl Cart {
+    fn new() -> Self {
+        Cart { items: Vec::new() }
+    }
+
+    fn add_item(&mut self, name: String, price: f64, quantity: u32) {
+        self.items.push(CartItem {
+            name,
+            price,
+            quantity,
+        });
+    }
+
+    fn total(&self) -> f64 {
+        // fold from 0.0 rather than sum(): an empty f64 sum is -0.0, which prints as "-0.00"
+        self.items
+            .iter()
+            .fold(0.0, |total, item| total + item.subtotal())
+    }
+}
+
+impl Default for Cart {
+    fn default() -> Self {
+        Cart::new()
+    }
+}
+
+// Itemized receipt, one line per item followed by the total
+impl fmt::Display for Cart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for item in &self.items {
+            writeln!(
+                f,
+                "{} x{} @ ${:.2} = ${:.2}",
+                item.name,
+                item.quantity,
+                item.price,
+                item.subtotal()
+            )?;
+        }
+        write!(f, "Total: ${:.2}", self.total())
+    }
 }
 
 // 4. TRAITS - Define shared behavior (like interfaces)
@@ -95,12 +603,18 @@ trait Vehicle {
     fn start(&self);
     fn stop(&self);
     fn honk(&self);
+
+    // Default method - types can override it with their own rule
+    fn needs_maintenance(&self, miles: u32) -> bool {
+        miles > 10000
+    }
 }
 
 // 5. MULTIPLE STRUCTS CAN IMPLEMENT THE SAME TRAIT
 struct Car {
     brand: String,
     model: String,
+    #[allow(dead_code)] // start() takes &self, so this stays false and is never read
     running: bool,
 }
 
@@ -126,6 +640,16 @@ impl Vehicle for Car {
     fn honk(&self) {
         println!("🔊 Beep beep!");
     }
+
+    // Some brands go longer between services than others
+    fn needs_maintenance(&self, miles: u32) -> bool {
+        let interval = match self.brand.as_str() {
+            "Toyota" | "Honda" => 15000,
+            "Ferrari" | "Lamborghini" => 5000,
+            _ => 10000,
+        };
+        miles > interval
+    }
 }
 
 struct Bicycle {
@@ -151,6 +675,12 @@ impl Vehicle for Bicycle {
     fn honk(&self) {
         println!("🔔 Ring ring!");
     }
+
+    // Chains wear faster with more gears: 3000 miles minus 100 per gear, at least 1000
+    fn needs_maintenance(&self, miles: u32) -> bool {
+        let interval = 3000u32.saturating_sub(100 * self.gears as u32).max(1000);
+        miles > interval
+    }
 }
 
 // 6. TRAIT WITH DEFAULT IMPLEMENTATION
@@ -169,6 +699,59 @@ impl Describable for Dog {
     }
 }
 
+// TRAIT OBJECTS - Unrelated types exposed through one interface
+trait Serializable {
+    // (field name, value) pairs in declaration order
+    fn to_pairs(&self) -> Vec<(String, String)>;
+}
+
+impl Serializable for Dog {
+    fn to_pairs(&self) -> Vec<(String, String)> {
+        vec![
+            (String::from("name"), self.name.clone()),
+            (String::from("breed"), self.breed.clone()),
+            (String::from("age"), self.age.to_string()),
+            (String::from("is_good_dog"), self.is_good_dog.to_string()),
+        ]
+    }
+}
+
+// Only the customer-facing fields; rate-limit bookkeeping and history are left out
+impl Serializable for BankAccount {
+    fn to_pairs(&self) -> Vec<(String, String)> {
+        vec![
+            (String::from("account_number"), self.account_number.clone()),
+            (String::from("owner"), self.owner.clone()),
+            (String::from("balance"), format!("{:.2}", self.balance)),
+            (
+                String::from("minimum_balance"),
+                format!("{:.2}", self.minimum_balance),
+            ),
+            (String::from("category"), self.category.clone()),
+            (
+                String::from("withdrawal_fee"),
+                format!("{:.2}", self.withdrawal_fee),
+            ),
+            (
+                String::from("minimum_transaction"),
+                format!("{:.2}", self.minimum_transaction),
+            ),
+        ]
+    }
+}
+
+// Works for any mix of types, since each is used through &dyn Serializable
+fn print_serialized(items: &[&dyn Serializable]) {
+    for item in items {
+        let fields: Vec<String> = item
+            .to_pairs()
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        println!("{}", fields.join(", "));
+    }
+}
+
 // 7. STRUCT WITH GENERIC TYPE
 #[derive(Debug)]
 struct Point<T> {
@@ -189,6 +772,97 @@ impl Point<f64> {
     }
 }
 
+// GENERIC FUNCTION OVER ANY STRUCT - The caller picks the field to compare
+fn min_max_by_key<T, K: Ord, F: Fn(&T) -> K>(items: &[T], key: F) -> Option<(&T, &T)> {
+    let min = items.iter().min_by_key(|item| key(item))?;
+    let max = items.iter().max_by_key(|item| key(item))?;
+    Some((min, max))
+}
+
+// One closure picks the group, the other the number to add up within it
+fn group_and_sum<T, K: Eq + Hash, KF: Fn(&T) -> K, VF: Fn(&T) -> f64>(
+    items: &[T],
+    key: KF,
+    value: VF,
+) -> HashMap<K, f64> {
+    let mut totals = HashMap::new();
+    for item in items {
+        *totals.entry(key(item)).or_insert(0.0) += value(item);
+    }
+    totals
+}
+
+// 8. STRUCT HOLDING CLOSURES - Each step is a boxed function applied in order
+struct Pipeline<T> {
+    steps: Vec<Box<dyn Fn(T) -> T>>,
+}
+
+impl<T> Pipeline<T> {
+    fn new() -> Self {
+        Pipeline { steps: Vec::new() }
+    }
+
+    // Takes self by value so calls can be chained
+    fn add_step(mut self, f: impl Fn(T) -> T + 'static) -> Self {
+        self.steps.push(Box::new(f));
+        self
+    }
+
+    fn run(&self, initial: T) -> T {
+        self.steps.iter().fold(initial, |value, step| step(value))
+    }
+}
+
+impl<T> Default for Pipeline<T> {
+    fn default() -> Self {
+        Pipeline::new()
+    }
+}
+
+// TABLE OUTPUT - Left-aligned columns as wide as their longest cell
+// Missing cells in short rows are padded as empty, so every line has the same width.
+fn format_table(headers: &[&str], rows: &[Vec<String>]) -> Vec<String> {
+    let columns = rows
+        .iter()
+        .map(Vec::len)
+        .chain([headers.len()])
+        .max()
+        .unwrap_or(0);
+    let mut widths = vec![0; columns];
+    for (i, header) in headers.iter().enumerate() {
+        widths[i] = header.chars().count();
+    }
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[&str]| {
+        let padded: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(i, &width)| format!("{:<width$}", cells.get(i).unwrap_or(&""), width = width))
+            .collect();
+        padded.join(" | ")
+    };
+
+    let mut lines = vec![format_row(headers)];
+    let dashes: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+    lines.push(dashes.join("-+-"));
+    for row in rows {
+        let cells: Vec<&str> = row.iter().map(String::as_str).collect();
+        lines.push(format_row(&cells));
+    }
+    lines
+}
+
+fn print_table(headers: &[&str], rows: &[Vec<String>]) {
+    for line in format_table(headers, rows) {
+        println!("{}", line);
+    }
+}
+
 // MAIN FUNCTION - Demonstrates everything
 fn main() {
     println!("=== Structs and Methods in Rust ===\n");
@@ -205,6 +879,7 @@ fn main() {
     my_dog.bark();
     my_dog.have_birthday();
     println!("Is good dog? {}", my_dog.is_good_dog);
+    println!("Display print: {}", my_dog);
     println!("Debug print: {:?}\n", my_dog);
 
     // 2. Using BankAccount struct
@@ -214,8 +889,8 @@ fn main() {
         String::from("Alice"),
     );
 
-    account.deposit(1000.0);
-    account.deposit(500.0);
+    account.deposit(1000.0).expect("Deposit should succeed");
+    account.deposit(500.0).expect("Deposit should succeed");
 
     match account.withdraw(300.0) {
         Ok(_) => println!("✅ Withdrawal successful"),
@@ -227,13 +902,197 @@ fn main() {
         Err(e) => println!("❌ Error: {}", e),
     }
 
-    println!("Final balance: ${:.2}\n", account.get_balance());
+    println!("Final balance: ${:.2}", account.get_balance());
+    println!("Statement:\n{}", account.export_csv());
+
+    // Accounts can require a minimum balance
+    let mut savings =
+        BankAccount::with_minimum_balance(String::from("654321"), String::from("Bob"), 100.0);
+    savings.deposit(250.0).expect("Deposit should succeed");
+
+    match savings.withdraw(200.0) {
+        Ok(_) => println!("✅ Withdrawal successful"),
+        Err(e) => println!("❌ Error: {}", e),
+    }
+    println!("Savings balance: ${:.2}\n", savings.get_balance());
+
+    // Fees and minimum transaction amounts
+    let mut checking =
+        BankAccount::with_fees(String::from("999999"), String::from("Erin"), 2.5, 10.0);
+    if let Err(e) = checking.deposit(5.0) {
+        println!("❌ Error: {}", e); // Below the minimum transaction
+    }
+    checking.deposit(100.0).expect("Deposit should succeed");
+    if let Err(e) = checking.withdraw(5.0) {
+        println!("❌ Error: {}", e);
+    }
+    checking.withdraw(20.0).expect("Withdrawal should succeed");
+    println!("Checking balance after fee: ${:.2}", checking.get_balance());
+    let by_kind = group_and_sum(
+        &checking.transactions,
+        |tx| tx.kind.as_str(),
+        |tx| tx.amount,
+    );
+    let mut by_kind: Vec<(&str, f64)> = by_kind.into_iter().collect();
+    by_kind.sort_by(|a, b| a.0.cmp(b.0));
+    for (kind, total) in by_kind {
+        println!("  Total {}: ${:.2}", kind, total);
+    }
+    println!();
+
+    // Rate limiting: at most 2 withdrawals per 10 ticks of the account's clock
+    let mut limited =
+        BankAccount::with_rate_limit(String::from("555555"), String::from("Frank"), 2, 10);
+    limited.deposit(100.0).expect("Deposit should succeed");
+    for _ in 0..3 {
+        if let Err(e) = limited.withdraw(10.0) {
+            println!("❌ Error: {}", e);
+        }
+    }
+    limited.advance_clock(10);
+    limited.withdraw(10.0).expect("Window has passed");
+    println!("Rate-limited balance: ${:.2}\n", limited.get_balance());
+
+    // Composition: a savings account built on top of a BankAccount
+    let mut interest_account = SavingsAccount::new(
+        BankAccount::new(String::from("777777"), String::from("Carol")),
+        0.06,
+    );
+    interest_account
+        .deposit(1000.0)
+        .expect("Deposit should succeed");
+    interest_account
+        .accrue_monthly()
+        .expect("Account has a balance");
+    if let Err(e) = interest_account.withdraw(5000.0) {
+        println!("❌ Error: {}", e);
+    }
+    println!(
+        "Balance after one month of interest: ${:.2}",
+        interest_account.get_balance()
+    );
+    for (month, balance) in interest_account.account.project_balance(0.06, 3) {
+        println!("  Month {}: ${:.2}", month, balance);
+    }
+    println!();
+
+    // Current state derived purely from an event log
+    let log = [("deposit", 200.0), ("withdraw", 50.0), ("deposit", 25.0)];
+    match replay_events(String::from("Grace"), &log) {
+        Ok(account) => println!("Replayed balance: ${:.2}", account.get_balance()),
+        Err(e) => println!("❌ Error: {}", e),
+    }
+    if let Err(e) = replay_events(
+        String::from("Grace"),
+        &[("deposit", 10.0), ("withdraw", 20.0)],
+    ) {
+        println!("❌ Error: {}", e);
+    }
+    println!();
+
+    // A bank holding several categorized accounts
+    let mut bank = Bank::new();
+    let openings = [
+        ("A1", "groceries", 120.0),
+        ("A2", "rent", 900.0),
+        ("A3", "groceries", 30.0),
+    ];
+    for (number, category, amount) in openings {
+        let mut acct = BankAccount::with_category(
+            String::from(number),
+            String::from("Dana"),
+            String::from(category),
+        );
+        acct.deposit(amount).expect("Deposit should succeed");
+        if let Err(e) = bank.open_account(acct) {
+            println!("❌ Error: {}", e);
+        }
+    }
+    if let Some(acct) = bank.account_mut("A3") {
+        acct.deposit(20.0).expect("Deposit should succeed");
+    }
+    println!("Totals by category: {:?}", bank.spending_by_category());
+    match bank.apply_interest_all(0.05, 12) {
+        Ok(paid) => println!("Interest paid across the bank: ${:.2}\n", paid),
+        Err(e) => println!("❌ Error: {}\n", e),
+    }
+
+    // Payroll-style batch: all payouts succeed or none do
+    let payroll = [(String::from("A1"), 50.0), (String::from("A3"), 25.0)];
+    if bank.batch_transfer("A2", &payroll).is_ok() {
+        println!("Payroll sent from A2");
+    }
+    let bad_batch = [(String::from("A1"), 10.0), (String::from("A9"), 10.0)];
+    if let Err(errors) = bank.batch_transfer("A2", &bad_batch) {
+        println!("❌ Batch rolled back: {}", errors.join("; "));
+    }
+    println!("Totals after payroll: {:?}\n", bank.spending_by_category());
+
+    // Loan amortization
+    println!("--- Loan Schedule ($1000 at 12% for 3 months) ---");
+    match loan_schedule(1000.0, 0.12, 3) {
+        Ok(rows) => {
+            for (month, payment, interest, principal, remaining) in rows {
+                println!(
+                    "Month {}: pay ${:.2} (interest ${:.2}, principal ${:.2}), left ${:.2}",
+                    month, payment, interest, principal, remaining
+                );
+            }
+        }
+        Err(e) => println!("❌ Error: {}", e),
+    }
+    println!();
+
+    // Money newtype: integer cents instead of f64
+    println!("--- Money Newtype ---");
+    println!("f64: 0.1 + 0.2 = {}", 0.1 + 0.2);
+    let total = Money::from_dollars(0.1).checked_add(Money::from_dollars(0.2));
+    if let Some(total) = total {
+        println!(
+            "Money: $0.10 + $0.20 = {} ({} dollars)",
+            total,
+            total.to_dollars()
+        );
+    }
+    if let Some(change) = Money::from_dollars(5.0).checked_sub(Money::from_dollars(7.25)) {
+        println!("Money: $5.00 - $7.25 = {}", change);
+    }
+    println!();
+
+    // Shopping cart with a Display receipt
+    println!("--- Shopping Cart ---");
+    let mut cart = Cart::new();
+    cart.add_item(String::from("Coffee beans"), 12.99, 1);
+    cart.add_item(String::from("Mug"), 7.5, 2);
+    println!("{}\n", cart);
+    let rows: Vec<Vec<String>> = cart
+        .items
+        .iter()
+        .map(|item| {
+            vec![
+                item.name.clone(),
+                item.quantity.to_string(),
+                format!("${:.2}", item.subtotal()),
+            ]
+        })
+        .collect();
+    print_table(&["Item", "Qty", "Subtotal"], &rows);
+    println!();
 
     // 3. Using Traits - Polymorphism
     println!("--- Vehicles Example (Traits) ---");
     let car = Car::new(String::from("Toyota"), String::from("Camry"));
     let bike = Bicycle::new(String::from("Trek"), 21);
 
+    println!(
+        "Car needs service at 12000 miles? {}",
+        car.needs_maintenance(12000)
+    );
+    println!(
+        "Bike needs service at 1200 miles? {}",
+        bike.needs_maintenance(1200)
+    );
+
     // Both implement Vehicle trait
     car.start();
     car.honk();
@@ -264,6 +1123,15 @@ fn main() {
     );
     another_dog.print_description();
 
+    println!("\n--- Serializable Trait ---");
+    let mut savings = BankAccount::with_category(
+        String::from("314159"),
+        String::from("Max's owner"),
+        String::from("savings"),
+    );
+    savings.deposit(42.0).expect("Deposit should succeed");
+    print_serialized(&[&another_dog, &savings]);
+
     // 6. Generic struct
     println!("\n--- Generic Struct Example ---");
     let int_point = Point::new(5, 10);
@@ -273,6 +1141,24 @@ fn main() {
     println!("Float point: {:?}", float_point);
     println!("Distance from origin: {:.2}", float_point.distance_from_origin());
 
+    // Pipeline of closures
+    println!("\n--- Pipeline Example ---");
+    let pipeline = Pipeline::new()
+        .add_step(|x: i32| x * 2) // double
+        .add_step(|x| x + 1) // add 1
+        .add_step(|x| x * x); // square
+    println!("Pipeline(3) = {}", pipeline.run(3)); // ((3 * 2) + 1)^2 = 49
+
+    // Generic min/max by a chosen field
+    let kennel = vec![
+        Dog::new(String::from("Rex"), String::from("Boxer"), 7),
+        Dog::new(String::from("Pip"), String::from("Corgi"), 1),
+        Dog::new(String::from("Luna"), String::from("Husky"), 4),
+    ];
+    if let Some((youngest, oldest)) = min_max_by_key(&kennel, |dog| dog.age) {
+        println!("\nYoungest: {}, oldest: {}", youngest, oldest);
+    }
+
     // 7. Ownership example with adopt_out (consumes self)
     println!("\n--- Ownership Example ---");
     let rescue_dog = Dog::new(
@@ -307,14 +1193,34 @@ mod tests {
         assert_eq!(dog.age, 2);
     }
 
+    #[test]
+    fn test_dog_display() {
+        let dog = Dog::new(String::from("Buddy"), String::from("Golden Retriever"), 3);
+        assert_eq!(
+            dog.to_string(),
+            "Buddy the 3-year-old Golden Retriever (a good dog)"
+        );
+    }
+
     #[test]
     fn test_bank_account_deposit() {
         let mut account = BankAccount::new(
             String::from("123"),
             String::from("Test"),
         );
-        account.deposit(100.0);
+        account.deposit(100.0).unwrap();
+        assert_eq!(account.get_balance(), 100.0);
+
+        assert_eq!(
+            account.deposit(0.0),
+            Err("Amount must be positive".to_string())
+        );
+        assert_eq!(
+            account.deposit(-5.0),
+            Err("Amount must be positive".to_string())
+        );
         assert_eq!(account.get_balance(), 100.0);
+        assert_eq!(account.transactions.len(), 1);
     }
 
     #[test]
@@ -323,7 +1229,7 @@ mod tests {
             String::from("123"),
             String::from("Test"),
         );
-        account.deposit(100.0);
+        account.deposit(100.0).unwrap();
         let result = account.withdraw(50.0);
         assert!(result.is_ok());
         assert_eq!(account.get_balance(), 50.0);
@@ -335,14 +1241,677 @@ mod tests {
             String::from("123"),
             String::from("Test"),
         );
-        account.deposit(100.0);
+        account.deposit(100.0).unwrap();
         let result = account.withdraw(200.0);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_minimum_balance() {
+        let mut account =
+            BankAccount::with_minimum_balance(String::from("123"), String::from("Test"), 100.0);
+        account.deposit(300.0).unwrap();
+
+        // Stays above the minimum
+        assert!(account.withdraw(150.0).is_ok());
+        assert_eq!(account.get_balance(), 150.0);
+
+        // Funds exist, but the minimum would be breached
+        let result = account.withdraw(100.0);
+        assert_eq!(
+            result,
+            Err("Withdrawal would drop below minimum balance".to_string())
+        );
+        assert_eq!(account.get_balance(), 150.0);
+    }
+
+    #[test]
+    fn test_default_account_has_no_minimum() {
+        let mut account = BankAccount::new(String::from("123"), String::from("Test"));
+        assert_eq!(account.minimum_balance, 0.0);
+        account.deposit(100.0).unwrap();
+        assert!(account.withdraw(100.0).is_ok());
+        assert_eq!(account.get_balance(), 0.0);
+    }
+
+    #[test]
+    fn test_withdrawal_fee_deducted() {
+        let mut account =
+            BankAccount::with_fees(String::from("123"), String::from("Test"), 2.0, 0.0);
+        account.deposit(100.0).unwrap();
+        account.withdraw(50.0).unwrap();
+        assert_eq!(account.get_balance(), 48.0);
+
+        // The fee counts toward available funds
+        assert_eq!(
+            account.withdraw(47.0),
+            Err("Insufficient funds".to_string())
+        );
+        assert!(account
+            .export_csv()
+            .ends_with("2,withdrawal,50.00\n3,fee,2.00\n"));
+    }
+
+    #[test]
+    fn test_minimum_transaction() {
+        let mut account =
+            BankAccount::with_fees(String::from("123"), String::from("Test"), 0.0, 10.0);
+        assert_eq!(
+            account.deposit(5.0),
+            Err("Amount is below the minimum transaction of $10.00".to_string())
+        );
+        assert_eq!(account.get_balance(), 0.0);
+
+        account.deposit(50.0).unwrap();
+        assert_eq!(
+            account.withdraw(9.99),
+            Err("Amount is below the minimum transaction of $10.00".to_string())
+        );
+        assert!(account.withdraw(10.0).is_ok());
+        assert_eq!(account.get_balance(), 40.0);
+    }
+
+    #[test]
+    fn test_default_account_has_no_fees() {
+        let mut account = BankAccount::new(String::from("123"), String::from("Test"));
+        assert_eq!(account.withdrawal_fee, 0.0);
+        assert_eq!(account.minimum_transaction, 0.0);
+        account.deposit(0.01).unwrap();
+        assert!(account.withdraw(0.01).is_ok());
+        assert_eq!(account.get_balance(), 0.0);
+    }
+
+    #[test]
+    fn test_export_csv() {
+        let mut account = BankAccount::new(String::from("123"), String::from("Test"));
+        account.deposit(100.0).unwrap();
+        account.deposit(25.5).unwrap();
+        account.withdraw(40.0).unwrap();
+        // Failed withdrawals are not recorded
+        assert!(account.withdraw(1000.0).is_err());
+
+        assert_eq!(
+            account.export_csv(),
+            "seq,type,amount\n1,deposit,100.00\n2,deposit,25.50\n3,withdrawal,40.00\n"
+        );
+    }
+
+    #[test]
+    fn test_export_csv_empty() {
+        let account = BankAccount::new(String::from("123"), String::from("Test"));
+        assert_eq!(account.export_csv(), "seq,type,amount\n");
+    }
+
+    fn categorized(number: &str, category: &str, amount: f64) -> BankAccount {
+        let mut account = BankAccount::with_category(
+            String::from(number),
+            String::from("Test"),
+            String::from(category),
+        );
+        if amount > 0.0 {
+            account.deposit(amount).unwrap();
+        }
+        account
+    }
+
+    #[test]
+    fn test_spending_by_category() {
+        let mut bank = Bank::new();
+        bank.open_account(categorized("1", "food", 100.0)).unwrap();
+        bank.open_account(categorized("2", "rent", 800.0)).unwrap();
+        bank.open_account(categorized("3", "food", 50.5)).unwrap();
+        bank.open_account(BankAccount::new(String::from("4"), String::from("Test")))
+            .unwrap();
+
+        let totals = bank.spending_by_category();
+        assert_eq!(totals.len(), 3);
+        assert_eq!(totals["food"], 150.5);
+        assert_eq!(totals["rent"], 800.0);
+        assert_eq!(totals["general"], 0.0);
+    }
+
+    #[test]
+    fn test_spending_single_category() {
+        let mut bank = Bank::new();
+        bank.open_account(categorized("1", "travel", 10.0)).unwrap();
+        bank.open_account(categorized("2", "travel", 15.0)).unwrap();
+        assert!(bank.open_account(categorized("2", "travel", 1.0)).is_err());
+
+        let totals = bank.spending_by_category();
+        assert_eq!(totals.len(), 1);
+        assert_eq!(totals["travel"], 25.0);
+    }
+
+    #[test]
+    fn test_apply_interest_all() {
+        let mut bank = Bank::new();
+        bank.open_account(categorized("1", "food", 1000.0)).unwrap();
+        bank.open_account(categorized("2", "rent", 500.0)).unwrap();
+        bank.open_account(categorized("3", "food", 0.0)).unwrap();
+        let before: HashMap<String, f64> = bank
+            .accounts
+            .iter()
+            .map(|(number, account)| (number.clone(), account.get_balance()))
+            .collect();
+
+        // 12% a year is 1% a month: 1000 -> 1020.10 and 500 -> 510.05 after two months
+        let total = bank.apply_interest_all(0.12, 2).unwrap();
+        assert_eq!(bank.accounts["1"].get_balance(), 1020.10);
+        assert_eq!(bank.accounts["2"].get_balance(), 510.05);
+        assert_eq!(bank.accounts["3"].get_balance(), 0.0);
+
+        let per_account: f64 = bank
+            .accounts
+            .iter()
+            .map(|(number, account)| account.get_balance() - before[number])
+            .sum();
+        assert!((total - per_account).abs() < 1e-9);
+        assert!((total - 30.15).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_apply_interest_all_edge_cases() {
+        let mut empty = Bank::new();
+        assert_eq!(empty.apply_interest_all(0.05, 12), Ok(0.0));
+
+        let mut bank = Bank::new();
+        bank.open_account(categorized("1", "food", 100.0)).unwrap();
+        assert!(bank.apply_interest_all(-0.01, 12).is_err());
+        assert!(bank.apply_interest_all(0.05, 0).is_err());
+        assert_eq!(bank.accounts["1"].get_balance(), 100.0);
+    }
+
+    #[test]
+    fn test_replay_events() {
+        let events = [
+            ("deposit", 100.0),
+            ("withdraw", 30.0),
+            ("deposit", 5.5),
+            ("withdraw", 75.5),
+        ];
+        let account = replay_events(String::from("Test"), &events).unwrap();
+        assert_eq!(account.owner, "Test");
+        assert_eq!(account.get_balance(), 0.0);
+        assert_eq!(account.transactions.len(), 4);
+
+        assert_eq!(
+            replay_events(String::from("Test"), &[])
+                .unwrap()
+                .get_balance(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_replay_events_overdraw_fails() {
+        let events = [
+            ("deposit", 50.0),
+            ("withdraw", 20.0),
+            ("withdraw", 40.0),
+            ("deposit", 100.0),
+        ];
+        assert_eq!(
+            replay_events(String::from("Test"), &events).err(),
+            Some(String::from(
+                "Event 2: withdraw of $40.00 failed: Insufficient funds"
+            ))
+        );
+        assert!(replay_events(String::from("Test"), &[("refund", 5.0)]).is_err());
+        assert!(replay_events(String::from("Test"), &[("deposit", -5.0)]).is_err());
+    }
+
+    fn strings(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|cell| cell.to_string()).collect()
+    }
+
+    #[test]
+    fn test_format_table_alignment() {
+        let rows = vec![strings(&["Coffee beans", "1"]), strings(&["Mug", "12"])];
+        let lines = format_table(&["Item", "Qty"], &rows);
+        assert_eq!(
+            lines,
+            vec![
+                "Item         | Qty",
+                "-------------+----",
+                "Coffee beans | 1  ",
+                "Mug          | 12 ",
+            ]
+        );
+        // Every separator sits in the same column
+        assert!(lines.iter().all(|line| line.find(['|', '+']) == Some(13)));
+    }
+
+    #[test]
+    fn test_format_table_ragged_rows() {
+        let rows = vec![strings(&["a"]), strings(&["bb", "cc", "dddd"])];
+        let lines = format_table(&["X", "Y"], &rows);
+        assert_eq!(lines[0], "X  | Y  |     ");
+        assert_eq!(lines[1], "---+----+-----");
+        assert_eq!(lines[2], "a  |    |     ");
+        assert_eq!(lines[3], "bb | cc | dddd");
+
+        assert_eq!(format_table(&["Only"], &[]), vec!["Only", "----"]);
+    }
+
+    fn payouts(items: &[(&str, f64)]) -> Vec<(String, f64)> {
+        items
+            .iter()
+            .map(|&(to, amount)| (to.to_string(), amount))
+            .collect()
+    }
+
+    #[test]
+    fn test_batch_transfer_success() {
+        let mut bank = Bank::new();
+        bank.open_account(categorized("src", "payroll", 1000.0))
+            .unwrap();
+        bank.open_account(categorized("a", "staff", 10.0)).unwrap();
+        bank.open_account(categorized("b", "staff", 0.0)).unwrap();
+
+        let batch = payouts(&[("a", 300.0), ("b", 250.0), ("a", 50.0)]);
+        assert_eq!(bank.batch_transfer("src", &batch), Ok(()));
+        assert_eq!(bank.accounts["src"].get_balance(), 400.0);
+        assert_eq!(bank.accounts["a"].get_balance(), 360.0);
+        assert_eq!(bank.accounts["b"].get_balance(), 250.0);
+        assert_eq!(bank.accounts["a"].transactions.len(), 3);
+    }
+
+    #[test]
+    fn test_batch_transfer_rolls_back() {
+        let mut bank = Bank::new();
+        bank.open_account(categorized("src", "payroll", 500.0))
+            .unwrap();
+        bank.open_account(categorized("a", "staff", 10.0)).unwrap();
+        bank.open_account(categorized("b", "staff", 20.0)).unwrap();
+
+        let batch = payouts(&[("a", 200.0), ("ghost", 5.0), ("b", 250.0), ("a", 100.0)]);
+        assert_eq!(
+            bank.batch_transfer("src", &batch),
+            Err(vec![
+                String::from("Payout 1 to ghost: Unknown account"),
+                String::from("Payout 3 to a: Insufficient funds"),
+            ])
+        );
+        assert_eq!(bank.accounts["src"].get_balance(), 500.0);
+        assert_eq!(bank.accounts["a"].get_balance(), 10.0);
+        assert_eq!(bank.accounts["b"].get_balance(), 20.0);
+        assert_eq!(bank.accounts["src"].transactions.len(), 1);
+        assert_eq!(bank.accounts["a"].transactions.len(), 1);
+
+        assert!(bank
+            .batch_transfer("nobody", &payouts(&[("a", 1.0)]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_batch_transfer_refused_deposit() {
+        let mut bank = Bank::new();
+        bank.open_account(categorized("src", "payroll", 100.0))
+            .unwrap();
+        bank.open_account(BankAccount::with_fees(
+            String::from("min"),
+            String::from("Test"),
+            0.0,
+            50.0,
+        ))
+        .unwrap();
+
+        assert_eq!(
+            bank.batch_transfer("src", &payouts(&[("min", 20.0)])),
+            Err(vec![String::from(
+                "Payout 0 to min: Amount is below the minimum transaction of $50.00"
+            )])
+        );
+        assert_eq!(bank.accounts["src"].get_balance(), 100.0);
+        assert_eq!(bank.accounts["src"].transactions.len(), 1);
+    }
+
+    #[test]
+    fn test_project_balance() {
+        let mut account = BankAccount::new(String::from("123"), String::from("Test"));
+        account.deposit(1000.0).unwrap();
+
+        let projection = account.project_balance(0.05, 12);
+        assert_eq!(projection.len(), 12);
+        assert_eq!(projection[0], (1, 1004.17));
+
+        let direct = 1000.0 * (1.0 + 0.05 / 12.0_f64).powi(12);
+        let (last_month, last_balance) = projection[11];
+        assert_eq!(last_month, 12);
+        assert!((last_balance - direct).abs() < 0.005);
+
+        // Projection doesn't change the account
+        assert_eq!(account.get_balance(), 1000.0);
+        assert!(account.project_balance(0.05, 0).is_empty());
+    }
+
+    #[test]
+    fn test_savings_account_accrual() {
+        let mut savings = SavingsAccount::new(
+            BankAccount::new(String::from("123"), String::from("Test")),
+            0.12,
+        );
+        savings.deposit(1000.0).unwrap();
+        savings.accrue_monthly().unwrap();
+        // 12% a year is 1% a month
+        assert!((savings.get_balance() - 1010.0).abs() < 1e-9);
+        assert!((savings.account.get_balance() - 1010.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_savings_account_delegation() {
+        let mut savings = SavingsAccount::new(
+            BankAccount::new(String::from("123"), String::from("Test")),
+            0.05,
+        );
+        savings.deposit(100.0).unwrap();
+        assert!(savings.withdraw(40.0).is_ok());
+        assert_eq!(savings.get_balance(), 60.0);
+        assert!(savings.withdraw(100.0).is_err());
+    }
+
+    #[test]
+    fn test_loan_schedule() {
+        let schedule = loan_schedule(10000.0, 0.06, 24).unwrap();
+        assert_eq!(schedule.len(), 24);
+
+        // Fixed payment every month
+        let payment = schedule[0].1;
+        assert!(schedule.iter().all(|row| (row.1 - payment).abs() < 1e-9));
+        assert!((payment - 443.21).abs() < 0.01);
+
+        // Interest plus principal make up each payment
+        assert!(schedule
+            .iter()
+            .all(|row| (row.2 + row.3 - row.1).abs() < 1e-9));
+
+        // Paid off by the final month
+        let (last_month, _, _, _, remaining) = schedule[23];
+        assert_eq!(last_month, 24);
+        assert!(remaining.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_loan_schedule_zero_interest_and_validation() {
+        let schedule = loan_schedule(1200.0, 0.0, 12).unwrap();
+        assert!(schedule.iter().all(|row| row.1 == 100.0 && row.2 == 0.0));
+        assert!(schedule[11].4.abs() < 1e-9);
+
+        assert!(loan_schedule(0.0, 0.05, 12).is_err());
+        assert!(loan_schedule(1000.0, -0.05, 12).is_err());
+        assert!(loan_schedule(1000.0, 0.05, 0).is_err());
+    }
+
+    #[test]
+    fn test_loan_schedule_rejects_non_finite() {
+        assert_eq!(
+            loan_schedule(f64::NAN, 0.05, 12),
+            Err(String::from("Principal must be a positive, finite amount"))
+        );
+        assert!(loan_schedule(f64::INFINITY, 0.05, 12).is_err());
+        assert!(loan_schedule(1000.0, f64::NAN, 12).is_err());
+        assert!(loan_schedule(1000.0, f64::INFINITY, 12).is_err());
+    }
+
+    #[test]
+    fn test_money_exact_addition() {
+        let total = Money::from_dollars(0.1)
+            .checked_add(Money::from_dollars(0.2))
+            .unwrap();
+        assert_eq!(total, Money::from_dollars(0.3));
+        assert_eq!(total, Money(30));
+        assert_eq!(total.to_dollars(), 0.3);
+        assert_eq!(Money(i64::MAX).checked_add(Money(1)), None);
+    }
+
+    #[test]
+    fn test_money_negative() {
+        let debt = Money::from_dollars(1.0)
+            .checked_sub(Money::from_dollars(2.5))
+            .unwrap();
+        assert_eq!(debt, Money(-150));
+        assert_eq!(debt.to_string(), "-$1.50");
+        assert_eq!(Money::from_dollars(-0.05).to_string(), "-$0.05");
+        assert_eq!(Money(i64::MIN).checked_sub(Money(1)), None);
+    }
+
+    #[test]
+    fn test_money_display() {
+        assert_eq!(Money(5).to_string(), "$0.05");
+        assert_eq!(Money(0).to_string(), "$0.00");
+        assert_eq!(Money::from_dollars(1234.5).to_string(), "$1234.50");
+    }
+
+    #[test]
+    fn test_empty_pipeline() {
+        let pipeline: Pipeline<i32> = Pipeline::default();
+        assert_eq!(pipeline.run(42), 42);
+    }
+
+    #[test]
+    fn test_multi_step_pipeline() {
+        let pipeline = Pipeline::new()
+            .add_step(|x: i32| x * 2)
+            .add_step(|x| x + 1)
+            .add_step(|x| x * x);
+        assert_eq!(pipeline.run(3), 49);
+        assert_eq!(pipeline.run(0), 1);
+
+        // Works for any T
+        let shout = Pipeline::new()
+            .add_step(|s: String| s.to_uppercase())
+            .add_step(|s| s + "!");
+        assert_eq!(shout.run(String::from("hi")), "HI!");
+    }
+
+    struct Scooter;
+
+    impl Vehicle for Scooter {
+        fn start(&self) {}
+        fn stop(&self) {}
+        fn honk(&self) {}
+    }
+
+    #[test]
+    fn test_default_maintenance_threshold() {
+        assert!(!Scooter.needs_maintenance(10000));
+        assert!(Scooter.needs_maintenance(10001));
+    }
+
+    #[test]
+    fn test_car_maintenance_by_brand() {
+        let toyota = Car::new(String::from("Toyota"), String::from("Camry"));
+        assert!(!toyota.needs_maintenance(15000));
+        assert!(toyota.needs_maintenance(15001));
+
+        let ferrari = Car::new(String::from("Ferrari"), String::from("F8"));
+        assert!(!ferrari.needs_maintenance(5000));
+        assert!(ferrari.needs_maintenance(5001));
+
+        let other = Car::new(String::from("Ford"), String::from("Focus"));
+        assert!(!other.needs_maintenance(10000));
+        assert!(other.needs_maintenance(10001));
+    }
+
+    #[test]
+    fn test_bicycle_maintenance_by_gears() {
+        let single_speed = Bicycle::new(String::from("Brompton"), 1);
+        assert!(!single_speed.needs_maintenance(2900));
+        assert!(single_speed.needs_maintenance(2901));
+
+        let road_bike = Bicycle::new(String::from("Trek"), 21);
+        assert!(!road_bike.needs_maintenance(1000));
+        assert!(road_bike.needs_maintenance(1001));
+    }
+
+    #[test]
+    fn test_cart_total() {
+        let mut cart = Cart::new();
+        cart.add_item(String::from("Apple"), 0.5, 4);
+        cart.add_item(String::from("Bread"), 3.25, 1);
+        cart.add_item(String::from("Cheese"), 6.0, 2);
+        assert_eq!(cart.total(), 17.25);
+
+        assert_eq!(Cart::default().total(), 0.0);
+    }
+
+    #[test]
+    fn test_cart_receipt() {
+        let mut cart = Cart::new();
+        cart.add_item(String::from("Apple"), 0.5, 4);
+        cart.add_item(String::from("Bread"), 3.25, 1);
+        assert_eq!(
+            cart.to_string(),
+            "Apple x4 @ $0.50 = $2.00\nBread x1 @ $3.25 = $3.25\nTotal: $5.25"
+        );
+
+        assert_eq!(Cart::new().to_string(), "Total: $0.00");
+    }
+
+    #[test]
+    fn test_group_and_sum() {
+        let expenses = [
+            ("food", 12.5),
+            ("rent", 900.0),
+            ("food", 7.5),
+            ("fun", 20.0),
+            ("food", 5.0),
+        ];
+        let totals = group_and_sum(&expenses, |&(category, _)| category, |&(_, amount)| amount);
+        assert_eq!(totals.len(), 3);
+        assert_eq!(totals["food"], 25.0);
+        assert_eq!(totals["rent"], 900.0);
+        assert_eq!(totals["fun"], 20.0);
+
+        // The key can be derived, not just copied out of a field
+        let by_size = group_and_sum(&expenses, |&(_, amount)| amount >= 100.0, |_| 1.0);
+        assert_eq!(by_size[&false], 4.0);
+        assert_eq!(by_size[&true], 1.0);
+
+        let none: [(&str, f64); 0] = [];
+        assert!(group_and_sum(&none, |&(category, _)| category, |&(_, amount)| amount).is_empty());
+    }
+
+    fn pair(key: &str, value: &str) -> (String, String) {
+        (key.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn test_dog_to_pairs() {
+        let dog = Dog::new(String::from("Rex"), String::from("Collie"), 4);
+        assert_eq!(
+            dog.to_pairs(),
+            vec![
+                pair("name", "Rex"),
+                pair("breed", "Collie"),
+                pair("age", "4"),
+                pair("is_good_dog", "true"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bank_account_to_pairs() {
+        let mut account =
+            BankAccount::with_fees(String::from("42"), String::from("Test"), 1.5, 5.0);
+        account.deposit(100.0).unwrap();
+        let pairs = account.to_pairs();
+        assert_eq!(
+            pairs,
+            vec![
+                pair("account_number", "42"),
+                pair("owner", "Test"),
+                pair("balance", "100.00"),
+                pair("minimum_balance", "0.00"),
+                pair("category", "general"),
+                pair("withdrawal_fee", "1.50"),
+                pair("minimum_transaction", "5.00"),
+            ]
+        );
+
+        // Both types fit in one slice of trait objects
+        let dog = Dog::new(String::from("Rex"), String::from("Collie"), 4);
+        let items: [&dyn Serializable; 2] = [&dog, &account];
+        assert_eq!(
+            items
+                .iter()
+                .map(|item| item.to_pairs().len())
+                .sum::<usize>(),
+            11
+        );
+    }
+
+    #[test]
+    fn test_min_max_by_key() {
+        let dogs = vec![
+            Dog::new(String::from("Rex"), String::from("Boxer"), 7),
+            Dog::new(String::from("Pip"), String::from("Corgi"), 1),
+            Dog::new(String::from("Luna"), String::from("Husky"), 4),
+        ];
+        let (youngest, oldest) = min_max_by_key(&dogs, |dog| dog.age).unwrap();
+        assert_eq!(youngest.name, "Pip");
+        assert_eq!(oldest.name, "Rex");
+
+        let (shortest, longest) = min_max_by_key(&dogs, |dog| dog.name.len()).unwrap();
+        assert_eq!(shortest.name, "Rex");
+        assert_eq!(longest.name, "Luna");
+    }
+
+    #[test]
+    fn test_min_max_by_key_edges() {
+        let one = [Dog::new(String::from("Solo"), String::from("Pug"), 2)];
+        let (min, max) = min_max_by_key(&one, |dog| dog.age).unwrap();
+        assert!(std::ptr::eq(min, max));
+
+        let none: [Dog; 0] = [];
+        assert!(min_max_by_key(&none, |dog| dog.age).is_none());
+    }
+
     #[test]
     fn test_point_distance() {
         let point = Point::new(3.0, 4.0);
         assert_eq!(point.distance_from_origin(), 5.0);
     }
+
+    #[test]
+    fn test_rate_limit_allows_up_to_max() {
+        let mut account =
+            BankAccount::with_rate_limit(String::from("123"), String::from("Test"), 3, 60);
+        account.deposit(100.0).unwrap();
+        for _ in 0..3 {
+            assert!(account.withdraw(10.0).is_ok());
+            account.advance_clock(1);
+        }
+        assert_eq!(account.get_balance(), 70.0);
+    }
+
+    #[test]
+    fn test_rate_limit_blocks_extra_withdrawal() {
+        let mut account =
+            BankAccount::with_rate_limit(String::from("123"), String::from("Test"), 2, 60);
+        account.deposit(100.0).unwrap();
+        account.withdraw(10.0).unwrap();
+        account.withdraw(10.0).unwrap();
+        assert_eq!(
+            account.withdraw(10.0),
+            Err("Rate limit exceeded".to_string())
+        );
+        assert_eq!(account.get_balance(), 80.0);
+    }
+
+    #[test]
+    fn test_rate_limit_resets_after_window() {
+        let mut account =
+            BankAccount::with_rate_limit(String::from("123"), String::from("Test"), 2, 60);
+        account.deposit(100.0).unwrap();
+        account.withdraw(10.0).unwrap();
+        account.advance_clock(30);
+        account.withdraw(10.0).unwrap();
+        assert!(account.withdraw(10.0).is_err());
+
+        // The first withdrawal leaves the window, the second is still in it
+        account.advance_clock(30);
+        assert!(account.withdraw(10.0).is_ok());
+        assert!(account.withdraw(10.0).is_err());
+    }
 }
@@ -1,7 +1,30 @@
 // Welcome to Rust! This is your first Rust program.
 // Let's explore some fundamental concepts.
+//
+// Run with `cargo run -- --repl` for an interactive calculator,
+// or `cargo run -- --calc` for a running-total calculator with undo.
+
+use std::io::{self, BufRead, Write};
 
 fn main() {
+    if std::env::args().any(|arg| arg == "--repl") {
+        run_repl("Commands: add X Y, divide X Y, quit", handle_command);
+        return;
+    }
+    if std::env::args().any(|arg| arg == "--calc") {
+        let mut calculator = Calculator::new();
+        run_repl("Commands: add X, sub X, mul X, div X, undo, quit", |line| {
+            if line.trim() == "quit" {
+                return String::from("Goodbye!");
+            }
+            match calculator.apply(line) {
+                Ok(value) => format!("= {}", value),
+                Err(e) => format!("Error: {}", e),
+            }
+        });
+        return;
+    }
+
     // 1. Variables and Mutability
     println!("=== Variables and Mutability ===");
     let x = 5; // Immutable by default
@@ -15,6 +38,7 @@ fn main() {
     // 2. Data Types
     println!("\n=== Data Types ===");
     let integer: i32 = 42;
+    #[allow(clippy::approx_constant)] // Just an example float, not meant to be PI
     let float: f64 = 3.14;
     let boolean: bool = true;
     let character: char = '🦀'; // Rust mascot Ferris!
@@ -26,6 +50,9 @@ fn main() {
     let string_slice: &str = "Hello"; // String slice (immutable)
     let string_object: String = String::from("World"); // Owned string
     println!("{}, {}!", string_slice, string_object);
+    for line in wrap_text("Rust strings are UTF-8 and growable", 12) {
+        println!("| {:<12} |", line);
+    }
 
     // 4. Arrays and Vectors
     println!("\n=== Arrays and Vectors ===");
@@ -34,11 +61,20 @@ fn main() {
     vector.push(7);
     println!("Array: {:?}", array);
     println!("Vector: {:?}", vector);
+    let first_three: Result<[i32; 3], String> = try_into_array(&vector[..3]);
+    println!("First three as an array: {:?}", first_three);
 
     // 5. Functions
     println!("\n=== Functions ===");
     let sum = add(10, 20);
     println!("10 + 20 = {}", sum);
+    for n in [7, 8] {
+        println!(
+            "{} doubled only if even: {}",
+            n,
+            apply_if(n, n % 2 == 0, |n| n * 2)
+        );
+    }
 
     // 6. Control Flow
     println!("\n=== Control Flow ===");
@@ -87,13 +123,47 @@ fn add(a: i32, b: i32) -> i32 {
     a + b // Last expression is returned (no semicolon)
 }
 
+/// Runs `f` on `value` only when `condition` holds; otherwise `f` is never called
+fn apply_if<T, F: Fn(T) -> T>(value: T, condition: bool, f: F) -> T {
+    if condition {
+        f(value)
+    } else {
+        value
+    }
+}
+
 /// Safely divides two numbers, returning None if divisor is zero
+/// (or if the result doesn't fit, which only happens for i32::MIN / -1)
 fn divide(dividend: i32, divisor: i32) -> Option<i32> {
-    if divisor == 0 {
-        None
-    } else {
-        Some(dividend / divisor)
+    dividend.checked_div(divisor)
+}
+
+/// Greedily packs words into lines of at most `width` characters.
+/// Words are never split, so a word longer than `width` (or any word when width is 0) gets its own line.
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        // +1 for the space that would join the word to the current line
+        if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
     }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Copies a slice into a fixed-size array; N is a const generic chosen by the caller
+fn try_into_array<T: Copy, const N: usize>(slice: &[T]) -> Result<[T; N], String> {
+    slice
+        .try_into()
+        .map_err(|_| format!("Expected {} elements, got {}", N, slice.len()))
 }
 
 /// Prints the length of a string slice (borrowed)
@@ -101,6 +171,115 @@ fn print_length(s: &String) {
     println!("Length of '{}' is {} characters", s, s.len());
 }
 
+/// Reads commands from stdin until "quit" or end of input, printing what `handle` returns
+fn run_repl(help: &str, mut handle: impl FnMut(&str) -> String) {
+    println!("Interactive mode. {}", help);
+    let stdin = io::stdin();
+    loop {
+        print!("> ");
+        io::stdout().flush().expect("Failed to flush stdout");
+
+        let mut line = String::new();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) => break, // End of input
+            Ok(_) => {}
+            Err(e) => {
+                println!("Failed to read input: {}", e);
+                break;
+            }
+        }
+
+        println!("{}", handle(&line));
+        if line.trim() == "quit" {
+            break;
+        }
+    }
+}
+
+/// Parses one REPL line and returns the text to print
+fn handle_command(line: &str) -> String {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+
+    match parts.as_slice() {
+        ["quit"] => String::from("Goodbye!"),
+        [command @ ("add" | "divide"), x, y] => {
+            let (x, y) = match (x.parse::<i32>(), y.parse::<i32>()) {
+                (Ok(x), Ok(y)) => (x, y),
+                _ => return format!("Expected two whole numbers, got '{}' and '{}'", x, y),
+            };
+            if *command == "add" {
+                match x.checked_add(y) {
+                    Some(sum) => format!("{} + {} = {}", x, y, sum),
+                    None => format!("{} + {} overflows a 32-bit integer", x, y),
+                }
+            } else {
+                match divide(x, y) {
+                    Some(value) => format!("{} / {} = {}", x, y, value),
+                    None if y == 0 => String::from("Cannot divide by zero!"),
+                    None => format!("{} / {} overflows a 32-bit integer", x, y),
+                }
+            }
+        }
+        [command @ ("add" | "divide"), ..] => format!("{} expects two integers", command),
+        [] => String::from("Please enter a command (add X Y, divide X Y, quit)"),
+        _ => format!(
+            "Unknown command '{}'. Try: add X Y, divide X Y, quit",
+            line.trim()
+        ),
+    }
+}
+
+/// A whole-number calculator with a running value that remembers how to undo each step
+struct Calculator {
+    value: i32,
+    history: Vec<(String, i32)>, // (command, value before it ran)
+}
+
+impl Calculator {
+    fn new() -> Self {
+        Calculator {
+            value: 0,
+            history: Vec::new(),
+        }
+    }
+
+    /// Runs "add X", "sub X", "mul X", "div X" or "undo" and returns the new value.
+    /// On error the running value is left unchanged.
+    fn apply(&mut self, command: &str) -> Result<f64, String> {
+        let parts: Vec<&str> = command.split_whitespace().collect();
+        let next = match parts.as_slice() {
+            ["undo"] => {
+                let (_, previous) = self
+                    .history
+                    .pop()
+                    .ok_or_else(|| String::from("Nothing to undo"))?;
+                self.value = previous;
+                return Ok(f64::from(self.value));
+            }
+            [op @ ("add" | "sub" | "mul" | "div"), x] => {
+                let x: i32 = x
+                    .parse()
+                    .map_err(|_| format!("Expected a whole number, got '{}'", x))?;
+                let result = match *op {
+                    "add" => self.value.checked_add(x),
+                    "sub" => self.value.checked_sub(x),
+                    "mul" => self.value.checked_mul(x),
+                    _ => divide(self.value, x),
+                };
+                // divide returns None for a zero divisor as well as for i32::MIN / -1
+                result.ok_or_else(|| match (*op, x) {
+                    ("div", 0) => String::from("Cannot divide by zero!"),
+                    _ => format!("'{}' overflows", command.trim()),
+                })?
+            }
+            _ => return Err(format!("Unknown command '{}'", command.trim())),
+        };
+        self.history.push((command.trim().to_string(), self.value));
+        self.value = next;
+        Ok(f64::from(self.value))
+    }
+}
+
 // Unit tests - run with 'cargo test'
 #[cfg(test)]
 mod tests {
@@ -116,5 +295,148 @@ mod tests {
     fn test_divide() {
         assert_eq!(divide(10, 2), Some(5));
         assert_eq!(divide(7, 0), None);
+        assert_eq!(divide(i32::MIN, -1), None);
+    }
+
+    #[test]
+    fn test_apply_if() {
+        assert_eq!(apply_if(5, true, |n| n * 2), 10);
+        assert_eq!(apply_if(5, false, |n| n * 2), 5);
+        assert_eq!(
+            apply_if(String::from("hi"), true, |s| s.to_uppercase()),
+            "HI"
+        );
+    }
+
+    #[test]
+    fn test_apply_if_skips_closure_when_false() {
+        let calls = std::cell::Cell::new(0);
+        let counted = |n: i32| {
+            calls.set(calls.get() + 1);
+            n + 1
+        };
+        assert_eq!(apply_if(1, false, counted), 1);
+        assert_eq!(calls.get(), 0);
+        assert_eq!(apply_if(1, true, counted), 2);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_wrap_text() {
+        assert_eq!(
+            wrap_text("the quick brown fox jumps over the lazy dog", 10),
+            vec!["the quick", "brown fox", "jumps over", "the lazy", "dog"]
+        );
+        assert_eq!(wrap_text("  short  line ", 20), vec!["short line"]);
+        assert!(wrap_text("", 10).is_empty());
+    }
+
+    #[test]
+    fn test_wrap_text_long_words() {
+        assert_eq!(
+            wrap_text("a supercalifragilistic word", 8),
+            vec!["a", "supercalifragilistic", "word"]
+        );
+        assert_eq!(wrap_text("one two", 0), vec!["one", "two"]);
+    }
+
+    #[test]
+    fn test_try_into_array() {
+        let array: [i32; 3] = try_into_array(&[1, 2, 3]).unwrap();
+        assert_eq!(array, [1, 2, 3]);
+
+        let empty: [char; 0] = try_into_array(&[]).unwrap();
+        assert_eq!(empty, []);
+    }
+
+    #[test]
+    fn test_try_into_array_length_mismatch() {
+        let result: Result<[i32; 4], String> = try_into_array(&[1, 2, 3]);
+        assert_eq!(result, Err(String::from("Expected 4 elements, got 3")));
+
+        let result: Result<[u8; 0], String> = try_into_array(&[1]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_calculator_sequence_and_undo() {
+        let mut calc = Calculator::new();
+        assert_eq!(calc.apply("add 10"), Ok(10.0));
+        assert_eq!(calc.apply("mul 3"), Ok(30.0));
+        assert_eq!(calc.apply("sub 5"), Ok(25.0));
+        assert_eq!(calc.apply("div 2"), Ok(12.0));
+        assert_eq!(calc.history.len(), 4);
+
+        assert_eq!(calc.apply("undo"), Ok(25.0));
+        assert_eq!(calc.apply("undo"), Ok(30.0));
+        assert_eq!(calc.apply("undo"), Ok(10.0));
+        assert_eq!(calc.apply("undo"), Ok(0.0));
+        assert_eq!(calc.apply("undo"), Err(String::from("Nothing to undo")));
+    }
+
+    #[test]
+    fn test_calculator_errors_keep_value() {
+        let mut calc = Calculator::new();
+        calc.apply("add 7").unwrap();
+        assert_eq!(
+            calc.apply("div 0"),
+            Err(String::from("Cannot divide by zero!"))
+        );
+        assert!(calc.apply("pow 2").is_err());
+        assert!(calc.apply("add seven").is_err());
+        assert!(calc.apply("mul 2147483647").is_err());
+        assert_eq!(calc.value, 7);
+
+        // Failed commands aren't recorded, so undo goes back past the add
+        assert_eq!(calc.apply("undo"), Ok(0.0));
+
+        // i32::MIN / -1 is an overflow, not a division by zero
+        calc.apply("sub 2147483647").unwrap();
+        calc.apply("sub 1").unwrap();
+        assert_eq!(
+            calc.apply("div -1"),
+            Err(String::from("'div -1' overflows"))
+        );
+    }
+
+    #[test]
+    fn test_handle_command_add() {
+        assert_eq!(handle_command("add 2 3"), "2 + 3 = 5");
+        assert_eq!(handle_command("  add -1 1\n"), "-1 + 1 = 0");
+    }
+
+    #[test]
+    fn test_handle_command_add_overflow() {
+        assert_eq!(
+            handle_command("add 2147483647 1"),
+            "2147483647 + 1 overflows a 32-bit integer"
+        );
+    }
+
+    #[test]
+    fn test_handle_command_divide() {
+        assert_eq!(handle_command("divide 10 2"), "10 / 2 = 5");
+        assert_eq!(handle_command("divide 7 0"), "Cannot divide by zero!");
+        assert_eq!(
+            handle_command("divide -2147483648 -1"),
+            "-2147483648 / -1 overflows a 32-bit integer"
+        );
+    }
+
+    #[test]
+    fn test_handle_command_quit() {
+        assert_eq!(handle_command("quit"), "Goodbye!");
+    }
+
+    #[test]
+    fn test_handle_command_malformed() {
+        assert_eq!(handle_command("add 2"), "add expects two integers");
+        assert_eq!(
+            handle_command("divide 1 2 3"),
+            "divide expects two integers"
+        );
+        assert!(handle_command("add two 3").starts_with("Expected two whole numbers"));
+        assert!(handle_command("multiply 2 3").starts_with("Unknown command"));
+        assert!(handle_command("").starts_with("Please enter a command"));
     }
 }